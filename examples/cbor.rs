@@ -0,0 +1,34 @@
+use inline_config::{Config, path};
+
+#[derive(Config)]
+#[config(src = include_env!("$CARGO_MANIFEST_DIR/examples/example_config.cbor"))]
+struct MyConfig;
+
+// Binary inline sources are written as byte string literals, here `{"offset": -10}`.
+#[derive(Config)]
+#[config(src = include_env!("$CARGO_MANIFEST_DIR/examples/example_config.cbor"))]
+#[config(format = "cbor", src = b"\xa1\x66offset\x29")]
+struct CalibratedConfig;
+
+fn main() {
+    let device: &str = MyConfig[path!(device)].into();
+    dbg!(device);
+
+    let offset: i16 = MyConfig[path!(offset)].into();
+    dbg!(offset);
+
+    let offset: i16 = CalibratedConfig[path!(offset)].into();
+    dbg!(offset);
+
+    let coefficients: Vec<f32> = MyConfig[path!(coefficients)].into();
+    dbg!(coefficients);
+
+    // CBOR byte strings can be accessed as byte slices, arrays or vectors.
+    let serial: &[u8] = MyConfig[path!(serial)].into();
+    dbg!(serial);
+    let serial: [u8; 4] = MyConfig[path!(serial)].into();
+    dbg!(serial);
+
+    let high: u8 = MyConfig[path!(thresholds.high)].into();
+    dbg!(high);
+}
@@ -0,0 +1,48 @@
+#![allow(unused)]
+
+use inline_config::{Config, FromConfig, path};
+
+// The file forms an array of rows, each being a table keyed by the header.
+#[derive(Config)]
+#[config(src = include_env!("$CARGO_MANIFEST_DIR/examples/example_config.csv"))]
+struct Countries;
+
+// `.tsv` files are separated by tabs. Other delimiters can be configured.
+#[derive(Config)]
+#[config(format(csv(delimiter = ';')))]
+#[config(src = "
+endpoint;requests_per_minute
+/login;10
+/search;600
+")]
+struct RateLimits;
+
+#[derive(FromConfig, Debug)]
+struct Country {
+    code: String,
+    name: String,
+    // Cells are typed when they parse as integers, floats or booleans.
+    dial: u32,
+    eu: bool,
+}
+
+#[derive(FromConfig, Debug)]
+struct RateLimit {
+    endpoint: &'static str,
+    requests_per_minute: u32,
+}
+
+fn main() {
+    let countries: Vec<Country> = Countries[path!()].into();
+    dbg!(countries);
+
+    let rate_limits: Vec<RateLimit> = RateLimits[path!()].into();
+    dbg!(rate_limits);
+
+    let population: f64 = Countries[path!(0.population_m)].into();
+    dbg!(population);
+
+    // Empty cells are null.
+    let population: Option<f64> = Countries[path!(2.population_m)].into();
+    dbg!(population);
+}
@@ -0,0 +1,58 @@
+use inline_config::{Config, path};
+
+#[derive(Config)]
+#[config(format = "toml")]
+#[config(src = r#"
+    released = 1979-05-27T07:32:00-08:00
+    meeting = 2024-02-29T09:30:00
+    birthday = 1979-05-27
+    alarm = 07:32:00.5
+"#)]
+struct Schedule;
+
+// `!!timestamp` values are datetimes as well, in UTC if the time zone is omitted.
+#[derive(Config)]
+#[config(format = "yaml")]
+#[config(src = r#"
+    deployed: !!timestamp 2001-12-14 21:59:43.10 -5
+"#)]
+struct Deployment;
+
+fn main() {
+    let released: chrono::DateTime<chrono::FixedOffset> = Schedule[path!(released)].into();
+    dbg!(released);
+    let released: chrono::DateTime<chrono::Utc> = Schedule[path!(released)].into();
+    dbg!(released);
+    let released: jiff::Timestamp = Schedule[path!(released)].into();
+    dbg!(released);
+    let released: time::OffsetDateTime = Schedule[path!(released)].into();
+    dbg!(released);
+
+    let meeting: chrono::NaiveDateTime = Schedule[path!(meeting)].into();
+    dbg!(meeting);
+    let meeting: jiff::civil::DateTime = Schedule[path!(meeting)].into();
+    dbg!(meeting);
+    let meeting: time::PrimitiveDateTime = Schedule[path!(meeting)].into();
+    dbg!(meeting);
+
+    let birthday: chrono::NaiveDate = Schedule[path!(birthday)].into();
+    dbg!(birthday);
+    let birthday: jiff::civil::Date = Schedule[path!(birthday)].into();
+    dbg!(birthday);
+    let birthday: time::Date = Schedule[path!(birthday)].into();
+    dbg!(birthday);
+
+    let alarm: chrono::NaiveTime = Schedule[path!(alarm)].into();
+    dbg!(alarm);
+    let alarm: jiff::civil::Time = Schedule[path!(alarm)].into();
+    dbg!(alarm);
+    let alarm: time::Time = Schedule[path!(alarm)].into();
+    dbg!(alarm);
+
+    // Strings are kept as written.
+    let alarm: &str = Schedule[path!(alarm)].into();
+    dbg!(alarm);
+
+    let deployed: jiff::Zoned = Deployment[path!(deployed)].into();
+    dbg!(deployed);
+}
@@ -0,0 +1,46 @@
+use inline_config::{Config, path};
+
+// The format is clear from the `.env` file name.
+#[derive(Config)]
+#[config(src = include_env!("$CARGO_MANIFEST_DIR/examples/.env"))]
+struct MyConfig;
+
+// Split keys with a separator to form nested tables.
+#[derive(Config)]
+#[config(format(dotenv(separator = "__")))]
+#[config(src = include_env!("$CARGO_MANIFEST_DIR/examples/.env"))]
+struct NestedConfig;
+
+// Variants like `.env.local` are recognized as well, and override former sources.
+#[derive(Config)]
+#[config(src = include_env!("$CARGO_MANIFEST_DIR/examples/.env"))]
+#[config(src = include_env!("$CARGO_MANIFEST_DIR/examples/.env.local"))]
+struct LocalConfig;
+
+fn main() {
+    // `export` prefixes are ignored.
+    let env: &str = MyConfig[path!(APP_ENV)].into();
+    dbg!(env);
+
+    // Single-quoted values are taken literally.
+    let log_dir: &str = MyConfig[path!(LOG_DIR)].into();
+    dbg!(log_dir);
+
+    // Double-quoted values support escapes and references.
+    let greeting: &str = MyConfig[path!(GREETING)].into();
+    dbg!(greeting);
+
+    // References only resolve to keys defined earlier in the file.
+    let url: &str = MyConfig[path!(DB__URL)].into();
+    dbg!(url);
+
+    let host: &str = NestedConfig[path!(DB.HOST)].into();
+    dbg!(host);
+
+    let env: &str = LocalConfig[path!(APP_ENV)].into();
+    dbg!(env);
+
+    // A `$` starting no reference is kept literally.
+    let price: &str = LocalConfig[path!(PRICE)].into();
+    dbg!(price);
+}
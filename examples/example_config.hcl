@@ -0,0 +1,29 @@
+# Shared with the infra team
+
+title = "HCL Example"
+
+server {
+  host  = "0.0.0.0"
+  port  = 8080
+  ports = [8000, 8001, 8002]
+  motd  = <<-EOT
+    Welcome!
+    EOT
+}
+
+service "http" "web" {
+  listen_addr = "127.0.0.1:8080"
+}
+
+service "http" "api" {
+  listen_addr = "127.0.0.1:8081"
+  limits      = { requests = 100, "burst-size" = -5 }
+}
+
+rule {
+  allow = true
+}
+
+rule {
+  allow = false
+}
@@ -0,0 +1,28 @@
+use inline_config::{Config, path};
+
+// Only the front matter of the page is parsed.
+// The format comes from the delimiter: `---` for YAML and `+++` for TOML.
+#[derive(Config)]
+#[config(src = front_matter!(include_env!("$CARGO_MANIFEST_DIR/examples/example_page.md")))]
+struct Page;
+
+#[derive(Config)]
+#[config(src = front_matter!(r#"+++
+title = "Changelog"
+weight = 20
++++
+
+# Changelog
+"#))]
+struct InlinePage;
+
+fn main() {
+    let title: &str = Page[path!(title)].into();
+    dbg!(title);
+
+    let tags: Vec<&str> = Page[path!(tags)].into();
+    dbg!(tags);
+
+    let weight: u32 = InlinePage[path!(weight)].into();
+    dbg!(weight);
+}
@@ -0,0 +1,27 @@
+use inline_config::{Config, path};
+
+#[derive(Config)]
+#[config(src = include_env!("$CARGO_MANIFEST_DIR/examples/example_config.hcl"))]
+struct MyConfig;
+
+fn main() {
+    // Blocks are tables.
+    let port: u16 = MyConfig[path!(server.port)].into();
+    dbg!(port);
+    let ports: Vec<u16> = MyConfig[path!(server.ports)].into();
+    dbg!(ports);
+    let motd: &str = MyConfig[path!(server.motd)].into();
+    dbg!(motd);
+
+    // Labeled blocks nest by their labels.
+    let listen_addr: &str = MyConfig[path!(service.http.web.listen_addr)].into();
+    dbg!(listen_addr);
+    let burst_size: i32 = MyConfig[path!(service.http.api.limits."burst-size")].into();
+    dbg!(burst_size);
+
+    // Repeated blocks form an array.
+    let allow: bool = MyConfig[path!(rule.1.allow)].into();
+    dbg!(allow);
+
+    // Non-literal expressions like `var.port` or `"${name}"` cause compile errors.
+}
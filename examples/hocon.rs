@@ -0,0 +1,27 @@
+use inline_config::{Config, path};
+
+#[derive(Config)]
+#[config(src = include_env!("$CARGO_MANIFEST_DIR/examples/example_config.conf"))]
+struct MyConfig;
+
+fn main() {
+    // Substitutions are resolved at compile time.
+    let url: &str = MyConfig[path!(service.url)].into();
+    dbg!(url);
+
+    // Objects of the same key are merged, including the included ones.
+    let loglevel: &str = MyConfig[path!(akka.loglevel)].into();
+    dbg!(loglevel);
+    let idle_timeout: &str = MyConfig[path!(akka.http.server."idle-timeout")].into();
+    dbg!(idle_timeout);
+    let request_timeout: &str = MyConfig[path!(akka.http.server."request-timeout")].into();
+    dbg!(request_timeout);
+
+    let paths: Vec<&str> = MyConfig[path!(paths)].into();
+    dbg!(paths);
+
+    let database_host: &str = MyConfig[path!(database.host)].into();
+    dbg!(database_host);
+    let database_name: &str = MyConfig[path!(database.name)].into();
+    dbg!(database_name);
+}
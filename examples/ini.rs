@@ -0,0 +1,29 @@
+use inline_config::{Config, path};
+
+// Sections are tables. Dotted section names nest.
+#[derive(Config)]
+#[config(src = include_env!("$CARGO_MANIFEST_DIR/examples/example_config.ini"))]
+struct MyConfig;
+
+// Values are typed as booleans or integers when they unambiguously parse as such.
+// Opt out to keep all values as strings.
+#[derive(Config)]
+#[config(format(ini(untyped)))]
+#[config(src = include_env!("$CARGO_MANIFEST_DIR/examples/example_config.ini"))]
+struct UntypedConfig;
+
+fn main() {
+    let title: &str = MyConfig[path!(title)].into();
+    dbg!(title);
+
+    let connection_max: u32 = MyConfig[path!(database.connection_max)].into();
+    dbg!(connection_max);
+    let enabled: bool = MyConfig[path!(database.enabled)].into();
+    dbg!(enabled);
+
+    let ip: &str = MyConfig[path!(servers.alpha.ip)].into();
+    dbg!(ip);
+
+    let connection_max: &str = UntypedConfig[path!(database.connection_max)].into();
+    dbg!(connection_max);
+}
@@ -0,0 +1,39 @@
+use inline_config::{Config, path};
+
+// JSON5 relaxes JSON syntax in a few ways.
+// JSON with comments (`.jsonc`) is also covered.
+#[derive(Config)]
+#[config(format = "json5")]
+#[config(src = r#"
+    // Line comments,
+    /* and block comments. */
+    {
+        unquoted: 'single-quoted',
+        "editor.fontSize": 14,
+        mask: 0xFF,
+        ratio: .5,
+        list: [1, 2, 3,],
+    }
+"#)]
+// Merges with later sources as usual.
+#[config(src = r#"
+    { "editor.fontSize": 16 }
+"#)]
+struct MyConfig;
+
+fn main() {
+    let unquoted: &str = MyConfig[path!(unquoted)].into();
+    dbg!(unquoted);
+
+    let font_size: u32 = MyConfig[path!("editor.fontSize")].into();
+    dbg!(font_size);
+
+    let mask: u8 = MyConfig[path!(mask)].into();
+    dbg!(mask);
+
+    let ratio: f64 = MyConfig[path!(ratio)].into();
+    dbg!(ratio);
+
+    let list: Vec<u32> = MyConfig[path!(list)].into();
+    dbg!(list);
+}
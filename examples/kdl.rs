@@ -0,0 +1,34 @@
+use inline_config::{Config, path};
+
+#[derive(Config)]
+#[config(format(kdl(array = "ports")))]
+#[config(src = include_env!("$CARGO_MANIFEST_DIR/examples/example_config.kdl"))]
+struct MyConfig;
+
+fn main() {
+    // A node with a single argument is that argument.
+    let title: &str = MyConfig[path!(title)].into();
+    dbg!(title);
+
+    let port: u16 = MyConfig[path!(server.port)].into();
+    dbg!(port);
+
+    // Listed nodes collect arguments of all of them into an array.
+    let ports: Vec<u16> = MyConfig[path!(server.ports)].into();
+    dbg!(ports);
+
+    let tls: bool = MyConfig[path!(server.tls.enabled)].into();
+    dbg!(tls);
+
+    let edition: u16 = MyConfig[path!(package."inline-config".edition)].into();
+    dbg!(edition);
+
+    let bob: &str = MyConfig[path!(user.bob.role)].into();
+    dbg!(bob);
+
+    let motd: &str = MyConfig[path!(motd)].into();
+    dbg!(motd);
+
+    let mask: u8 = MyConfig[path!(mask)].into();
+    dbg!(mask);
+}
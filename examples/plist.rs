@@ -0,0 +1,45 @@
+use inline_config::{Config, path};
+
+#[derive(Config)]
+#[config(src = include_env!("$CARGO_MANIFEST_DIR/examples/example_config.plist"))]
+struct MyConfig;
+
+// ASCII property lists have integers, strings, arrays and dictionaries.
+#[derive(Config)]
+#[config(format = "plist")]
+#[config(src = r#"
+{
+    Name = Helper;
+    Port = 49152;
+    Hosts = (localhost, "127.0.0.1");
+}
+"#)]
+struct AsciiConfig;
+
+fn main() {
+    let version: &str = MyConfig[path!(CFBundleVersion)].into();
+    dbg!(version);
+
+    let agent: bool = MyConfig[path!(LSUIElement)].into();
+    dbg!(agent);
+
+    let port: u16 = MyConfig[path!(HelperPort)].into();
+    dbg!(port);
+
+    // Dates are datetimes in UTC, which also convert into strings.
+    let release_date: &str = MyConfig[path!(ReleaseDate)].into();
+    dbg!(release_date);
+
+    // Data is a byte string.
+    let signing_key: &[u8] = MyConfig[path!(SigningKey)].into();
+    dbg!(signing_key);
+
+    let hosts: Vec<&str> = MyConfig[path!(AllowedHosts)].into();
+    dbg!(hosts);
+
+    let port: u16 = AsciiConfig[path!(Port)].into();
+    dbg!(port);
+
+    let hosts: Vec<&str> = AsciiConfig[path!(Hosts)].into();
+    dbg!(hosts);
+}
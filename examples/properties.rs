@@ -0,0 +1,38 @@
+use inline_config::{Config, path};
+
+// Dotted keys form nested tables. All values are strings.
+#[derive(Config)]
+#[config(src = include_env!("$CARGO_MANIFEST_DIR/examples/example_config.properties"))]
+struct MyConfig;
+
+// Flat keys suit layouts where a key holds both a value and nested keys.
+#[derive(Config)]
+#[config(format(properties(flat)))]
+#[config(src = "
+    logging.level = INFO
+    logging.level.root = DEBUG
+")]
+struct LoggingConfig;
+
+fn main() {
+    // `=`, `:` and whitespace all separate keys from values.
+    let port: &str = MyConfig[path!(server.port)].into();
+    dbg!(port);
+    let host: &str = MyConfig[path!(server.host)].into();
+    dbg!(host);
+
+    // Lines ending with `\` continue on the next line.
+    let motd: &str = MyConfig[path!(server.motd)].into();
+    dbg!(motd);
+
+    // Escapes are supported in both keys and values.
+    let owner: &str = MyConfig[path!(server.owner)].into();
+    dbg!(owner);
+    let path: &str = MyConfig[path!("path with spaces")].into();
+    dbg!(path);
+
+    let level: &str = LoggingConfig[path!("logging.level")].into();
+    dbg!(level);
+    let root_level: &str = LoggingConfig[path!("logging.level.root")].into();
+    dbg!(root_level);
+}
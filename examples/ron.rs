@@ -0,0 +1,38 @@
+use inline_config::{Config, path};
+
+// Struct names are ignored; named fields form a table.
+#[derive(Config)]
+#[config(format = "ron")]
+#[config(src = r#"
+    GameConfig(
+        title: "RON Example",
+        window: (width: 1280, height: 720),
+        spawn: (0.5, -1.0),
+        difficulty: Some(3),
+        cheats: None,
+        mode: Fast,
+        keys: { 'w': "up", 's': "down" },
+    )
+"#)]
+struct MyConfig;
+
+fn main() {
+    let title: &str = MyConfig[path!(title)].into();
+    dbg!(title);
+
+    // Tuples are arrays.
+    let x: f32 = MyConfig[path!(spawn.0)].into();
+    dbg!(x);
+
+    // `Some(_)` is transparent.
+    let difficulty: u8 = MyConfig[path!(difficulty)].into();
+    dbg!(difficulty);
+
+    // Unit variants are strings of their names.
+    let mode: &str = MyConfig[path!(mode)].into();
+    dbg!(mode);
+
+    // Chars are strings, both as values and as map keys.
+    let up: &str = MyConfig[path!(keys.w)].into();
+    dbg!(up);
+}
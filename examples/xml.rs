@@ -0,0 +1,38 @@
+use inline_config::{Config, path};
+
+// The root element becomes the only key of the root table.
+#[derive(Config)]
+#[config(src = include_env!("$CARGO_MANIFEST_DIR/examples/example_config.xml"))]
+struct MyConfig;
+
+// Conventions can be configured.
+// `array` makes the named elements always form arrays, even when they occur only once.
+#[derive(Config)]
+#[config(format(xml(attr_prefix = "_", text_key = "value", array = "channel")))]
+#[config(src = include_env!("$CARGO_MANIFEST_DIR/examples/example_config.xml"))]
+struct CustomConfig;
+
+fn main() {
+    // An element with neither attributes nor child elements is its text.
+    let name: &str = MyConfig[path!(device.name)].into();
+    dbg!(name);
+
+    // Attributes are prefixed with `@` by default.
+    let vendor: &str = MyConfig[path!(device."@vendor")].into();
+    dbg!(vendor);
+
+    // Text next to attributes or child elements goes under `#text` by default.
+    let firmware: &str = MyConfig[path!(device.firmware."#text")].into();
+    dbg!(firmware);
+
+    // Repeated elements form an array.
+    let baud: &str = MyConfig[path!(device.port.1.baud)].into();
+    dbg!(baud);
+
+    let version: &str = CustomConfig[path!(device.firmware._version)].into();
+    dbg!(version);
+    let firmware: &str = CustomConfig[path!(device.firmware.value)].into();
+    dbg!(firmware);
+    let channel: &str = CustomConfig[path!(device.channel.0._id)].into();
+    dbg!(channel);
+}
@@ -0,0 +1,38 @@
+use inline_config::{Config, path};
+
+// Select a single document by its index.
+#[derive(Config)]
+#[config(
+    format(yaml(document = 1)),
+    src = include_env!("$CARGO_MANIFEST_DIR/examples/example_manifests.yaml")
+)]
+struct Deployment;
+
+// Expose all documents as an array.
+#[derive(Config)]
+#[config(
+    format(yaml(array)),
+    src = include_env!("$CARGO_MANIFEST_DIR/examples/example_manifests.yaml")
+)]
+struct Manifests;
+
+// Merge all documents in order, as if they were separate sources.
+#[derive(Config)]
+#[config(
+    format(yaml(merge)),
+    src = include_env!("$CARGO_MANIFEST_DIR/examples/example_manifests.yaml")
+)]
+struct Merged;
+
+fn main() {
+    let replicas: u32 = Deployment[path!(spec.replicas)].into();
+    dbg!(replicas);
+
+    let target_port: u16 = Manifests[path!(0.spec.ports.0.targetPort)].into();
+    dbg!(target_port);
+
+    let kind: &str = Merged[path!(kind)].into();
+    dbg!(kind);
+    let port: u16 = Merged[path!(spec.ports.0.port)].into();
+    dbg!(port);
+}
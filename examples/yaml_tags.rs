@@ -0,0 +1,35 @@
+use inline_config::{Config, path};
+
+// Tags other than the standard ones need to be registered.
+#[derive(Config)]
+#[config(
+    src = include_env!("$CARGO_MANIFEST_DIR/examples/example_service.yaml"),
+    yaml_tag(name = "!env", action = "env"),
+    yaml_tag(name = "!include", action = "include"),
+    yaml_tag(name = "!Ref", action = "ignore")
+)]
+struct Service;
+
+fn main() {
+    // Read from the environment at compile time.
+    let name: &str = Service[path!(name)].into();
+    dbg!(name);
+
+    // `!!str` keeps the value from being read as a float.
+    let version: &str = Service[path!(version)].into();
+    dbg!(version);
+
+    let started: &str = Service[path!(started)].into();
+    dbg!(started);
+
+    // `!!binary` decodes base64 into bytes.
+    let checksum: &[u8] = Service[path!(checksum)].into();
+    dbg!(checksum);
+
+    // Included files are resolved relative to the including one.
+    let database_port: u16 = Service[path!(database.port)].into();
+    dbg!(database_port);
+
+    let deploy: &str = Service[path!(deploy)].into();
+    dbg!(deploy);
+}
@@ -1,6 +1,6 @@
 use crate::format::Format;
-use crate::path::Key;
-use crate::value::{Map, Value};
+use crate::path::{Key, Path};
+use crate::value::{Datetime, Map, Value};
 use darling::{FromDeriveInput, FromMeta};
 
 #[derive(FromDeriveInput)]
@@ -10,41 +10,115 @@ struct ConfigItem {
     format: Option<Format>,
     #[darling(multiple)]
     src: Vec<darling::util::SpannedValue<Source>>,
+    #[darling(multiple)]
+    unset: Vec<darling::util::SpannedValue<Path>>,
+    #[darling(default)]
+    deny_non_finite: bool,
+    #[darling(default)]
+    deny_null: bool,
+    #[cfg(feature = "yaml")]
+    #[darling(multiple)]
+    yaml_tag: Vec<crate::format::YamlTag>,
 }
 
 enum Source {
     Include(std::path::PathBuf),
-    Lit(String),
+    Lit(Vec<u8>),
+    // Extracted when parsing the attribute, along with its delimiter and the directory of its source.
+    FrontMatter {
+        delimiter: &'static str,
+        content: Vec<u8>,
+        dir: Option<std::path::PathBuf>,
+    },
 }
 
 impl Source {
-    fn content(&self) -> std::io::Result<std::borrow::Cow<'_, str>> {
+    fn current_dir() -> std::io::Result<std::path::PathBuf> {
+        // Rust analyzer hasn't implemented `Span::file()`.
+        // https://github.com/rust-lang/rust-analyzer/issues/15950
+        Ok(
+            std::path::PathBuf::from(proc_macro2::Span::call_site().file())
+                .parent()
+                .ok_or(std::io::ErrorKind::AddrNotAvailable)?
+                .to_path_buf(),
+        )
+    }
+
+    fn resolve_path(path: &std::path::Path) -> std::io::Result<std::path::PathBuf> {
+        // Resolve the path relative to the current file.
+        if path.is_absolute() {
+            Ok(path.to_path_buf())
+        } else {
+            Ok(Self::current_dir()?.join(path))
+        }
+    }
+
+    fn content(&self) -> std::io::Result<std::borrow::Cow<'_, [u8]>> {
         match self {
-            Self::Include(path) => {
-                // Resolve the path relative to the current file.
-                let path = if path.is_absolute() {
-                    path.clone()
-                } else {
-                    // Rust analyzer hasn't implemented `Span::file()`.
-                    // https://github.com/rust-lang/rust-analyzer/issues/15950
-                    std::path::PathBuf::from(proc_macro2::Span::call_site().file())
-                        .parent()
-                        .ok_or(std::io::ErrorKind::AddrNotAvailable)?
-                        .join(path)
-                };
-                Ok(std::borrow::Cow::Owned(std::fs::read_to_string(path)?))
+            Self::Include(path) => Ok(std::borrow::Cow::Owned(std::fs::read(Self::resolve_path(
+                path,
+            )?)?)),
+            Self::Lit(content) | Self::FrontMatter { content, .. } => {
+                Ok(std::borrow::Cow::Borrowed(content))
+            }
+        }
+    }
+
+    // Splits off the front matter, returning its delimiter along with it.
+    fn front_matter(content: &[u8]) -> Option<(&'static str, &[u8])> {
+        let content = std::str::from_utf8(content).ok()?;
+        let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+        let mut lines = content.split_inclusive('\n');
+        let first = lines.next()?;
+        let delimiter = match first.trim_end() {
+            "---" => "---",
+            "+++" => "+++",
+            _ => return None,
+        };
+        let start = first.len();
+        let mut end = start;
+        for line in lines {
+            if line.trim_end() == delimiter {
+                return Some((delimiter, &content.as_bytes()[start..end]));
             }
-            Self::Lit(content) => Ok(std::borrow::Cow::Borrowed(content)),
+            end += line.len();
         }
+        None
     }
 
-    fn extension(&self) -> Option<&std::ffi::OsStr> {
+    // The directory which relative references in the content are resolved against.
+    fn dir(&self) -> Option<std::path::PathBuf> {
         match self {
-            Self::Include(path) => path.extension(),
-            Self::Lit(_) => None,
+            Self::Include(path) => Some(Self::resolve_path(path).ok()?.parent()?.to_path_buf()),
+            Self::Lit(_) => Self::current_dir().ok(),
+            Self::FrontMatter { dir, .. } => dir.clone(),
         }
     }
 
+    fn format(&self) -> Result<Option<Format>, String> {
+        Ok(match self {
+            // Dotfiles like `.env` or `.env.local` are named after the format instead.
+            Self::Include(path) => path
+                .extension()
+                .and_then(std::ffi::OsStr::to_str)
+                .and_then(Format::from_extension)
+                .or_else(|| {
+                    let name = path.file_name()?.to_str()?.strip_prefix('.')?;
+                    Format::from_extension(name.split('.').next()?)
+                }),
+            Self::Lit(_) => None,
+            // The format comes from the delimiter rather than the extension of the document.
+            Self::FrontMatter { delimiter, .. } => {
+                let extension = if *delimiter == "---" { "yaml" } else { "toml" };
+                Some(Format::from_extension(extension).ok_or_else(|| {
+                    format!(
+                        "Front matter delimited by `{delimiter}` requires feature `{extension}`"
+                    )
+                })?)
+            }
+        })
+    }
+
     fn resolve_env(path: &str) -> Result<String, std::env::VarError> {
         let mut chars = path.chars().peekable();
         let mut resolved = String::new();
@@ -89,10 +163,34 @@ impl FromMeta for Source {
                 Self::resolve_env(&syn::parse2::<syn::LitStr>(tokens.clone())?.value())
                     .map_err(|e| syn::Error::new_spanned(expr, e))?,
             ))),
+            syn::Expr::Macro(syn::ExprMacro {
+                mac: syn::Macro { path, tokens, .. },
+                ..
+            }) if path.is_ident("front_matter") => {
+                let source = Self::from_expr(&syn::parse2(tokens.clone())?)?;
+                let content = source
+                    .content()
+                    .map_err(|e| syn::Error::new_spanned(expr, e))?;
+                let (delimiter, content) = Self::front_matter(&content).ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        expr,
+                        "Missing front matter delimited by `---` or `+++`",
+                    )
+                })?;
+                Ok(Self::FrontMatter {
+                    delimiter,
+                    content: content.to_vec(),
+                    dir: source.dir(),
+                })
+            }
             syn::Expr::Lit(syn::ExprLit {
                 lit: syn::Lit::Str(lit_str),
                 ..
-            }) => Ok(Self::Lit(lit_str.value())),
+            }) => Ok(Self::Lit(lit_str.value().into_bytes())),
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::ByteStr(lit_byte_str),
+                ..
+            }) => Ok(Self::Lit(lit_byte_str.value())),
             syn::Expr::Lit(lit) => Self::from_value(&lit.lit),
             syn::Expr::Group(group) => Self::from_expr(&group.expr),
             _ => Err(darling::Error::unexpected_expr_type(expr)),
@@ -104,16 +202,21 @@ impl FromMeta for Source {
 pub fn config(item: syn::DeriveInput) -> syn::Result<syn::ItemConst> {
     let config_item: ConfigItem = ConfigItem::from_derive_input(&item)?;
     let format = config_item.format.map(Ok).unwrap_or_else(|| {
-        let mut extensions = config_item.src.iter().filter_map(|source| {
-            source
-                .extension()
-                .and_then(std::ffi::OsStr::to_str)
-                .and_then(Format::from_extension)
-        });
-        let first = extensions
+        let formats = config_item
+            .src
+            .iter()
+            .filter_map(|source| {
+                source
+                    .format()
+                    .map_err(|e| syn::Error::new(source.span(), e))
+                    .transpose()
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+        let mut formats = formats.into_iter();
+        let first = formats
             .next()
             .ok_or(syn::Error::new_spanned(&item, "Missing format"))?;
-        let other = extensions.find(|x| x != &first);
+        let other = formats.find(|x| x != &first);
         if let Some(other) = other {
             Err(syn::Error::new_spanned(
                 &item,
@@ -123,8 +226,20 @@ pub fn config(item: syn::DeriveInput) -> syn::Result<syn::ItemConst> {
             Ok(first)
         }
     })?;
+    // Custom tags are registered apart from the format, which may come from extensions.
+    // Other arms are unreachable when YAML is the only format enabled.
+    #[cfg(feature = "yaml")]
+    #[allow(unreachable_patterns)]
+    let format = match format {
+        Format::Yaml(options) => Format::Yaml(options.with_tags(config_item.yaml_tag)),
+        _ if !config_item.yaml_tag.is_empty() => Err(syn::Error::new_spanned(
+            &item,
+            "`yaml_tag` only applies to YAML sources",
+        ))?,
+        format => format,
+    };
     let mut errors = darling::Error::accumulator();
-    let value: Value = config_item
+    let mut value: Value = config_item
         .src
         .into_iter()
         .filter_map(|source| {
@@ -132,12 +247,31 @@ pub fn config(item: syn::DeriveInput) -> syn::Result<syn::ItemConst> {
                 Ok(source
                     .content()
                     .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
-                    .and_then(|content| format.parse(content.as_ref()))
+                    .and_then(|content| format.parse(content.as_ref(), source.dir().as_deref()))
                     .map_err(|e| syn::Error::new(source.span(), e))?)
             })
         })
         .sum();
     errors.finish()?;
+    for path in &config_item.unset {
+        if value.remove_path(path.keys()).is_none() {
+            Err(syn::Error::new(path.span(), "No value to unset"))?;
+        }
+    }
+    if config_item.deny_non_finite
+        && let Some(path) =
+            value.find_path(&|value| matches!(value, Value::Float(value) if !value.is_finite()))
+    {
+        Err(syn::Error::new_spanned(
+            &item,
+            format!("Non-finite float at `{path}`"),
+        ))?;
+    }
+    if config_item.deny_null
+        && let Some(path) = value.find_path(&|value| matches!(value, Value::Nil))
+    {
+        Err(syn::Error::new_spanned(&item, format!("Null at `{path}`")))?;
+    }
     Ok(value.to_item_const(&config_item.ident))
 }
 
@@ -145,6 +279,7 @@ struct ConvertImpl {
     ty: syn::Type,
     expr: syn::Expr,
     generics: syn::Generics,
+    attrs: Vec<syn::Attribute>,
 }
 
 trait ValueVariant {
@@ -187,11 +322,35 @@ trait ValueVariant {
                         }
                     }
                 };
+                // The `Convertible` bound keeps the impl apart from `From<T> for Option<T>`,
+                // as generated types never implement it.
+                let item_impl_option: syn::ItemImpl = if let Value::Nil = value {
+                    syn::parse_quote! {
+                        impl<T: ::inline_config::__private::Convertible> From<#child_ident> for Option<T> {
+                            fn from(_value: #child_ident) -> Self {
+                                None
+                            }
+                        }
+                    }
+                } else {
+                    syn::parse_quote! {
+                        impl<T: ::inline_config::__private::Convertible> From<#child_ident> for Option<T>
+                        where
+                            #child_ident: Into<T>,
+                        {
+                            fn from(value: #child_ident) -> Self {
+                                Some(value.into())
+                            }
+                        }
+                    }
+                };
                 let item_mod: syn::ItemMod = syn::parse_quote! {
                     pub mod #mod_ident {
                         pub struct #child_ident;
 
                         #item_const
+
+                        #item_impl_option
                     }
                 };
                 ((child_ty, child_expr), (item_impl_index, item_mod))
@@ -200,15 +359,23 @@ trait ValueVariant {
         let item_impls_from: Vec<syn::ItemImpl> = self
             .convert_impls(&children_tys, &children_exprs)
             .into_iter()
-            .map(|ConvertImpl { ty, expr, generics }| {
-                syn::parse_quote! {
-                    impl #generics From<#ident> for #ty {
-                        fn from(_value: #ident) -> Self {
-                            #expr
+            .map(
+                |ConvertImpl {
+                     ty,
+                     expr,
+                     generics,
+                     attrs,
+                 }| {
+                    syn::parse_quote! {
+                        #(#attrs)*
+                        impl #generics From<#ident> for #ty {
+                            fn from(_value: #ident) -> Self {
+                                #expr
+                            }
                         }
                     }
-                }
-            })
+                },
+            )
             .collect();
         syn::parse_quote! {
             const _: () = {
@@ -277,6 +444,8 @@ impl ValueVariant for Value {
             Self::NegInt(value) => value.convert_impls(children_tys, children_exprs),
             Self::Float(value) => value.convert_impls(children_tys, children_exprs),
             Self::String(value) => value.convert_impls(children_tys, children_exprs),
+            Self::Bytes(value) => value.convert_impls(children_tys, children_exprs),
+            Self::Datetime(value) => value.convert_impls(children_tys, children_exprs),
             Self::Array(value) => value.convert_impls(children_tys, children_exprs),
             Self::Table(value) => value.convert_impls(children_tys, children_exprs),
         }
@@ -290,6 +459,8 @@ impl ValueVariant for Value {
             Self::NegInt(value) => value.children(),
             Self::Float(value) => value.children(),
             Self::String(value) => value.children(),
+            Self::Bytes(value) => value.children(),
+            Self::Datetime(value) => value.children(),
             Self::Array(value) => value.children(),
             Self::Table(value) => value.children(),
         }
@@ -308,72 +479,146 @@ impl ValueVariant for bool {
             ty: syn::parse_quote! { bool },
             expr: syn::parse_quote! { #self },
             generics: syn::Generics::default(),
+            attrs: Vec::new(),
         }]
         .into()
     }
 }
 
-impl ValueVariant for u64 {
+// Offers a conversion into each type only if it represents the value exactly,
+// while `Lossy<T>` always takes the `as` cast.
+fn cast_impls(value: &syn::Expr, casts: Vec<(syn::Type, bool)>) -> Vec<ConvertImpl> {
+    casts
+        .into_iter()
+        .flat_map(|(ty, exact)| {
+            [
+                exact.then(|| ConvertImpl {
+                    ty: ty.clone(),
+                    expr: syn::parse_quote! { #value as #ty },
+                    generics: syn::Generics::default(),
+                    attrs: Vec::new(),
+                }),
+                Some(ConvertImpl {
+                    ty: syn::parse_quote! { ::inline_config::Lossy<#ty> },
+                    expr: syn::parse_quote! { ::inline_config::Lossy(#value as #ty) },
+                    generics: syn::Generics::default(),
+                    attrs: Vec::new(),
+                }),
+            ]
+        })
+        .flatten()
+        .collect()
+}
+
+// The pointer width is only known where the config is used, so `usize` and `isize` convert exactly
+// on targets of the widths which fit the value, gated by `cfg`.
+// `fits` tells whether the value fits in 16, 32 and 64 bits respectively.
+fn pointer_sized_impls(value: &syn::Expr, ty: syn::Type, fits: [bool; 3]) -> Vec<ConvertImpl> {
+    const WIDTHS: [&str; 3] = ["16", "32", "64"];
+    let widths = &WIDTHS[fits.iter().position(|&fits| fits).unwrap_or(WIDTHS.len())..];
+    let mut impls = cast_impls(value, [(ty, !widths.is_empty())].into());
+    if !widths.is_empty() {
+        impls[0].attrs = vec![syn::parse_quote! {
+            #[cfg(any(#(target_pointer_width = #widths),*))]
+        }];
+    }
+    impls
+}
+
+// Whether a float of `digits` significant bits represents the integer exactly.
+fn fits_float(magnitude: u128, digits: u32) -> bool {
+    magnitude == 0 || u128::BITS - magnitude.leading_zeros() - magnitude.trailing_zeros() <= digits
+}
+
+impl ValueVariant for u128 {
     fn convert_impls(
         &self,
         _children_tys: &[syn::Type],
         _children_exprs: &[syn::Expr],
     ) -> Vec<ConvertImpl> {
-        macro_rules! cast_impl {
-            ($ty:ty) => {
-                ConvertImpl {
-                    ty: syn::parse_quote! { $ty },
-                    expr: syn::parse_quote! { #self as $ty },
-                    generics: syn::Generics::default(),
-                }
-            };
-        }
-        [
-            cast_impl!(u8),
-            cast_impl!(u16),
-            cast_impl!(u32),
-            cast_impl!(u64),
-            cast_impl!(u128),
-            cast_impl!(usize),
-            cast_impl!(i8),
-            cast_impl!(i16),
-            cast_impl!(i32),
-            cast_impl!(i64),
-            cast_impl!(i128),
-            cast_impl!(isize),
-            cast_impl!(f32),
-            cast_impl!(f64),
-        ]
-        .into()
+        let value = syn::parse_quote! { #self };
+        let mut impls = cast_impls(
+            &value,
+            [
+                (syn::parse_quote! { u8 }, u8::try_from(*self).is_ok()),
+                (syn::parse_quote! { u16 }, u16::try_from(*self).is_ok()),
+                (syn::parse_quote! { u32 }, u32::try_from(*self).is_ok()),
+                (syn::parse_quote! { u64 }, u64::try_from(*self).is_ok()),
+                (syn::parse_quote! { u128 }, true),
+                (syn::parse_quote! { i8 }, i8::try_from(*self).is_ok()),
+                (syn::parse_quote! { i16 }, i16::try_from(*self).is_ok()),
+                (syn::parse_quote! { i32 }, i32::try_from(*self).is_ok()),
+                (syn::parse_quote! { i64 }, i64::try_from(*self).is_ok()),
+                (syn::parse_quote! { i128 }, i128::try_from(*self).is_ok()),
+                (
+                    syn::parse_quote! { f32 },
+                    fits_float(*self, f32::MANTISSA_DIGITS),
+                ),
+                (
+                    syn::parse_quote! { f64 },
+                    fits_float(*self, f64::MANTISSA_DIGITS),
+                ),
+            ]
+            .into(),
+        );
+        impls.extend(pointer_sized_impls(
+            &value,
+            syn::parse_quote! { usize },
+            [
+                u16::try_from(*self).is_ok(),
+                u32::try_from(*self).is_ok(),
+                u64::try_from(*self).is_ok(),
+            ],
+        ));
+        impls.extend(pointer_sized_impls(
+            &value,
+            syn::parse_quote! { isize },
+            [
+                i16::try_from(*self).is_ok(),
+                i32::try_from(*self).is_ok(),
+                i64::try_from(*self).is_ok(),
+            ],
+        ));
+        impls
     }
 }
 
-impl ValueVariant for i64 {
+impl ValueVariant for i128 {
     fn convert_impls(
         &self,
         _children_tys: &[syn::Type],
         _children_exprs: &[syn::Expr],
     ) -> Vec<ConvertImpl> {
-        macro_rules! cast_impl {
-            ($ty:ty) => {
-                ConvertImpl {
-                    ty: syn::parse_quote! { $ty },
-                    expr: syn::parse_quote! { #self as $ty },
-                    generics: syn::Generics::default(),
-                }
-            };
-        }
-        [
-            cast_impl!(i8),
-            cast_impl!(i16),
-            cast_impl!(i32),
-            cast_impl!(i64),
-            cast_impl!(i128),
-            cast_impl!(isize),
-            cast_impl!(f32),
-            cast_impl!(f64),
-        ]
-        .into()
+        let value = syn::parse_quote! { #self };
+        let mut impls = cast_impls(
+            &value,
+            [
+                (syn::parse_quote! { i8 }, i8::try_from(*self).is_ok()),
+                (syn::parse_quote! { i16 }, i16::try_from(*self).is_ok()),
+                (syn::parse_quote! { i32 }, i32::try_from(*self).is_ok()),
+                (syn::parse_quote! { i64 }, i64::try_from(*self).is_ok()),
+                (syn::parse_quote! { i128 }, true),
+                (
+                    syn::parse_quote! { f32 },
+                    fits_float(self.unsigned_abs(), f32::MANTISSA_DIGITS),
+                ),
+                (
+                    syn::parse_quote! { f64 },
+                    fits_float(self.unsigned_abs(), f64::MANTISSA_DIGITS),
+                ),
+            ]
+            .into(),
+        );
+        impls.extend(pointer_sized_impls(
+            &value,
+            syn::parse_quote! { isize },
+            [
+                i16::try_from(*self).is_ok(),
+                i32::try_from(*self).is_ok(),
+                i64::try_from(*self).is_ok(),
+            ],
+        ));
+        impls
     }
 }
 
@@ -383,16 +628,30 @@ impl ValueVariant for f64 {
         _children_tys: &[syn::Type],
         _children_exprs: &[syn::Expr],
     ) -> Vec<ConvertImpl> {
-        macro_rules! cast_impl {
-            ($ty:ty) => {
-                ConvertImpl {
-                    ty: syn::parse_quote! { $ty },
-                    expr: syn::parse_quote! { #self as $ty },
-                    generics: syn::Generics::default(),
-                }
-            };
-        }
-        [cast_impl!(f32), cast_impl!(f64)].into()
+        // Non-finite floats have no literals.
+        let value: syn::Expr = if self.is_nan() {
+            syn::parse_quote! { ::std::primitive::f64::NAN }
+        } else if *self == f64::INFINITY {
+            syn::parse_quote! { ::std::primitive::f64::INFINITY }
+        } else if *self == f64::NEG_INFINITY {
+            syn::parse_quote! { ::std::primitive::f64::NEG_INFINITY }
+        } else {
+            syn::parse_quote! { #self }
+        };
+        cast_impls(
+            &value,
+            [
+                // Decimal floats are rarely exact anyway, so `0.1` reads as the nearest `f32`,
+                // as long as it neither overflows nor underflows to zero.
+                (
+                    syn::parse_quote! { f32 },
+                    !self.is_finite()
+                        || ((*self as f32).is_finite() && (*self == 0.0 || *self as f32 != 0.0)),
+                ),
+                (syn::parse_quote! { f64 }, true),
+            ]
+            .into(),
+        )
     }
 }
 
@@ -407,17 +666,291 @@ impl ValueVariant for String {
                 ty: syn::parse_quote! { &'static str },
                 expr: syn::parse_quote! { #self },
                 generics: syn::Generics::default(),
+                attrs: Vec::new(),
             },
             ConvertImpl {
                 ty: syn::parse_quote! { String },
                 expr: syn::parse_quote! { #self.to_string() },
                 generics: syn::Generics::default(),
+                attrs: Vec::new(),
+            },
+        ]
+        .into()
+    }
+}
+
+impl ValueVariant for Vec<u8> {
+    fn convert_impls(
+        &self,
+        _children_tys: &[syn::Type],
+        _children_exprs: &[syn::Expr],
+    ) -> Vec<ConvertImpl> {
+        let lit = syn::LitByteStr::new(self, proc_macro2::Span::call_site());
+        let len = self.len();
+        [
+            ConvertImpl {
+                ty: syn::parse_quote! { &'static [u8] },
+                expr: syn::parse_quote! { #lit },
+                generics: syn::Generics::default(),
+                attrs: Vec::new(),
+            },
+            ConvertImpl {
+                ty: syn::parse_quote! { [u8; #len] },
+                expr: syn::parse_quote! { *#lit },
+                generics: syn::Generics::default(),
+                attrs: Vec::new(),
+            },
+            ConvertImpl {
+                ty: syn::parse_quote! { Vec<u8> },
+                expr: syn::parse_quote! { #lit.to_vec() },
+                generics: syn::Generics::default(),
+                attrs: Vec::new(),
             },
         ]
         .into()
     }
 }
 
+impl ValueVariant for Datetime {
+    fn convert_impls(
+        &self,
+        _children_tys: &[syn::Type],
+        _children_exprs: &[syn::Expr],
+    ) -> Vec<ConvertImpl> {
+        let text = &self.text;
+        let convert_impls = Vec::from([
+            ConvertImpl {
+                ty: syn::parse_quote! { &'static str },
+                expr: syn::parse_quote! { #text },
+                generics: syn::Generics::default(),
+                attrs: Vec::new(),
+            },
+            ConvertImpl {
+                ty: syn::parse_quote! { String },
+                expr: syn::parse_quote! { #text.to_string() },
+                generics: syn::Generics::default(),
+                attrs: Vec::new(),
+            },
+        ]);
+        #[cfg(feature = "chrono")]
+        let convert_impls: Vec<_> = convert_impls
+            .into_iter()
+            .chain(self.chrono_convert_impls())
+            .collect();
+        #[cfg(feature = "jiff")]
+        let convert_impls: Vec<_> = convert_impls
+            .into_iter()
+            .chain(self.jiff_convert_impls())
+            .collect();
+        #[cfg(feature = "time")]
+        let convert_impls: Vec<_> = convert_impls
+            .into_iter()
+            .chain(self.time_convert_impls())
+            .collect();
+        convert_impls
+    }
+}
+
+// Components are validated on construction, hence the unwrapping never panics,
+// as long as instants beyond the range of a type get no conversion into it.
+impl Datetime {
+    #[cfg(feature = "chrono")]
+    fn chrono_convert_impls(&self) -> Vec<ConvertImpl> {
+        let convert_impl = |ty: syn::Type, expr: syn::Expr| ConvertImpl {
+            ty,
+            expr,
+            generics: syn::Generics::default(),
+            attrs: Vec::new(),
+        };
+        let date = self.date.map(|date| {
+            let (year, month, day) = (date.year as i32, date.month as u32, date.day as u32);
+            quote::quote! { ::chrono::NaiveDate::from_ymd_opt(#year, #month, #day).unwrap() }
+        });
+        let time = self.time.map(|time| {
+            let (hour, minute, second, nanosecond) = (
+                time.hour as u32,
+                time.minute as u32,
+                time.second as u32,
+                time.nanosecond,
+            );
+            quote::quote! {
+                ::chrono::NaiveTime::from_hms_nano_opt(#hour, #minute, #second, #nanosecond).unwrap()
+            }
+        });
+        let offset = self.offset.map(|offset| offset as i32 * 60);
+        match (date, time, offset) {
+            (Some(date), None, None) => vec![convert_impl(
+                syn::parse_quote! { ::chrono::NaiveDate },
+                syn::parse_quote! { #date },
+            )],
+            (None, Some(time), None) => vec![convert_impl(
+                syn::parse_quote! { ::chrono::NaiveTime },
+                syn::parse_quote! { #time },
+            )],
+            (Some(date), Some(time), None) => vec![convert_impl(
+                syn::parse_quote! { ::chrono::NaiveDateTime },
+                syn::parse_quote! { ::chrono::NaiveDateTime::new(#date, #time) },
+            )],
+            (Some(date), Some(time), Some(offset)) => {
+                let datetime = quote::quote! {
+                    ::chrono::TimeZone::from_local_datetime(
+                        &::chrono::FixedOffset::east_opt(#offset).unwrap(),
+                        &::chrono::NaiveDateTime::new(#date, #time),
+                    )
+                    .unwrap()
+                };
+                vec![
+                    convert_impl(
+                        syn::parse_quote! { ::chrono::DateTime<::chrono::FixedOffset> },
+                        syn::parse_quote! { #datetime },
+                    ),
+                    convert_impl(
+                        syn::parse_quote! { ::chrono::DateTime<::chrono::Utc> },
+                        syn::parse_quote! { #datetime.with_timezone(&::chrono::Utc) },
+                    ),
+                ]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    #[cfg(feature = "jiff")]
+    fn jiff_convert_impls(&self) -> Vec<ConvertImpl> {
+        let convert_impl = |ty: syn::Type, expr: syn::Expr| ConvertImpl {
+            ty,
+            expr,
+            generics: syn::Generics::default(),
+            attrs: Vec::new(),
+        };
+        let date = self.date.map(|date| {
+            let (year, month, day) = (date.year as i16, date.month as i8, date.day as i8);
+            quote::quote! { ::jiff::civil::Date::new(#year, #month, #day).unwrap() }
+        });
+        let time = self.time.map(|time| {
+            let (hour, minute, second, nanosecond) = (
+                time.hour as i8,
+                time.minute as i8,
+                time.second as i8,
+                time.nanosecond as i32,
+            );
+            quote::quote! { ::jiff::civil::Time::new(#hour, #minute, #second, #nanosecond).unwrap() }
+        });
+        let offset = self.offset.map(|offset| offset as i32 * 60);
+        match (date, time, offset) {
+            (Some(date), None, None) => vec![convert_impl(
+                syn::parse_quote! { ::jiff::civil::Date },
+                syn::parse_quote! { #date },
+            )],
+            (None, Some(time), None) => vec![convert_impl(
+                syn::parse_quote! { ::jiff::civil::Time },
+                syn::parse_quote! { #time },
+            )],
+            (Some(date), Some(time), None) => vec![convert_impl(
+                syn::parse_quote! { ::jiff::civil::DateTime },
+                syn::parse_quote! { ::jiff::civil::DateTime::from_parts(#date, #time) },
+            )],
+            // Instants after `jiff::Timestamp::MAX`, like `9999-12-31T23:00:00Z`, are not timestamps.
+            (Some(_), Some(_), Some(_)) if !self.is_before_jiff_max() => Vec::new(),
+            (Some(date), Some(time), Some(offset)) => {
+                let offset = quote::quote! { ::jiff::tz::Offset::from_seconds(#offset).unwrap() };
+                let timestamp = quote::quote! {
+                    #offset
+                        .to_timestamp(::jiff::civil::DateTime::from_parts(#date, #time))
+                        .unwrap()
+                };
+                vec![
+                    convert_impl(
+                        syn::parse_quote! { ::jiff::Timestamp },
+                        syn::parse_quote! { #timestamp },
+                    ),
+                    convert_impl(
+                        syn::parse_quote! { ::jiff::Zoned },
+                        syn::parse_quote! {
+                            #timestamp.to_zoned(::jiff::tz::TimeZone::fixed(#offset))
+                        },
+                    ),
+                ]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    // Compares the instant in UTC with `9999-12-30T22:00:00.999999999Z`,
+    // the latest instant `jiff::Timestamp` represents.
+    #[cfg(feature = "jiff")]
+    fn is_before_jiff_max(&self) -> bool {
+        // Days since an arbitrary epoch, with years starting from March.
+        fn days(year: u16, month: u8, day: u8) -> i64 {
+            let (year, month) = match month {
+                1 | 2 => (year as i64 - 1, month as i64 + 9),
+                _ => (year as i64, month as i64 - 3),
+            };
+            year * 365 + year.div_euclid(4) - year.div_euclid(100)
+                + year.div_euclid(400)
+                + (153 * month + 2) / 5
+                + day as i64
+        }
+        let (Some(date), Some(time)) = (self.date, self.time) else {
+            return true;
+        };
+        let seconds = days(date.year, date.month, date.day) * 86400
+            + time.hour as i64 * 3600
+            + time.minute as i64 * 60
+            + time.second as i64
+            - self.offset.unwrap_or_default() as i64 * 60;
+        seconds <= days(9999, 12, 30) * 86400 + 22 * 3600
+    }
+
+    #[cfg(feature = "time")]
+    fn time_convert_impls(&self) -> Vec<ConvertImpl> {
+        let convert_impl = |ty: syn::Type, expr: syn::Expr| ConvertImpl {
+            ty,
+            expr,
+            generics: syn::Generics::default(),
+            attrs: Vec::new(),
+        };
+        let date = self.date.map(|date| {
+            let (year, month, day) = (date.year as i32, date.month, date.day);
+            quote::quote! {
+                ::time::Date::from_calendar_date(
+                    #year,
+                    ::time::Month::try_from(#month).unwrap(),
+                    #day,
+                )
+                .unwrap()
+            }
+        });
+        let time = self.time.map(|time| {
+            let (hour, minute, second, nanosecond) =
+                (time.hour, time.minute, time.second, time.nanosecond);
+            quote::quote! { ::time::Time::from_hms_nano(#hour, #minute, #second, #nanosecond).unwrap() }
+        });
+        let offset = self.offset.map(|offset| offset as i32 * 60);
+        match (date, time, offset) {
+            (Some(date), None, None) => vec![convert_impl(
+                syn::parse_quote! { ::time::Date },
+                syn::parse_quote! { #date },
+            )],
+            (None, Some(time), None) => vec![convert_impl(
+                syn::parse_quote! { ::time::Time },
+                syn::parse_quote! { #time },
+            )],
+            (Some(date), Some(time), None) => vec![convert_impl(
+                syn::parse_quote! { ::time::PrimitiveDateTime },
+                syn::parse_quote! { ::time::PrimitiveDateTime::new(#date, #time) },
+            )],
+            (Some(date), Some(time), Some(offset)) => vec![convert_impl(
+                syn::parse_quote! { ::time::OffsetDateTime },
+                syn::parse_quote! {
+                    ::time::PrimitiveDateTime::new(#date, #time)
+                        .assume_offset(::time::UtcOffset::from_whole_seconds(#offset).unwrap())
+                },
+            )],
+            _ => Vec::new(),
+        }
+    }
+}
+
 impl ValueVariant for Vec<Value> {
     fn convert_impls(
         &self,
@@ -432,6 +965,7 @@ impl ValueVariant for Vec<Value> {
             generics: syn::parse_quote! {
                 <T: #(From<#children_tys>)+*>
             },
+            attrs: Vec::new(),
         }]
         .into()
     }
@@ -460,6 +994,7 @@ impl ValueVariant for Map<String, Value> {
                 generics: syn::parse_quote! {
                     <T: #(From<#children_tys>)+*>
                 },
+                attrs: Vec::new(),
             },
             ConvertImpl {
                 ty: syn::parse_quote! { ::std::collections::BTreeMap<String, T> },
@@ -469,6 +1004,7 @@ impl ValueVariant for Map<String, Value> {
                 generics: syn::parse_quote! {
                     <T: #(From<#children_tys>)+*>
                 },
+                attrs: Vec::new(),
             },
             #[cfg(feature = "indexmap")]
             ConvertImpl {
@@ -479,6 +1015,7 @@ impl ValueVariant for Map<String, Value> {
                 generics: syn::parse_quote! {
                     <T: #(From<#children_tys>)+*>
                 },
+                attrs: Vec::new(),
             },
             #[cfg(feature = "indexmap")]
             ConvertImpl {
@@ -489,6 +1026,7 @@ impl ValueVariant for Map<String, Value> {
                 generics: syn::parse_quote! {
                     <T: #(From<#children_tys>)+*>
                 },
+                attrs: Vec::new(),
             },
         ].into()
     }
@@ -499,3 +1037,143 @@ impl ValueVariant for Map<String, Value> {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(expr: &str) -> darling::Result<Source> {
+        Source::from_expr(&syn::parse_str(expr).unwrap())
+    }
+
+    #[test]
+    fn front_matter() {
+        let Source::FrontMatter {
+            delimiter, content, ..
+        } = source("front_matter!(\"+++\\na = 1\\n+++\\nbody\")").unwrap()
+        else {
+            panic!("not front matter");
+        };
+        assert_eq!(delimiter, "+++");
+        assert_eq!(content, b"a = 1\n");
+        assert!(source("front_matter!(\"a = 1\")").is_err());
+        assert!(source("front_matter!(\"---\\na: 1\\n\")").is_err());
+    }
+
+    #[cfg(any(feature = "json", feature = "toml"))]
+    fn error(item: syn::DeriveInput) -> String {
+        config(item).unwrap_err().to_string()
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn deny_non_finite() {
+        assert_eq!(
+            error(syn::parse_quote! {
+                #[config(format = "toml", deny_non_finite)]
+                #[config(src = "[limits]\nratio = nan\nscales = [1.0, -inf]")]
+                struct Limits;
+            }),
+            "Non-finite float at `limits.ratio`"
+        );
+        assert_eq!(
+            error(syn::parse_quote! {
+                #[config(format = "toml", deny_non_finite)]
+                #[config(src = "scales = [1.0, inf]")]
+                struct Limits;
+            }),
+            "Non-finite float at `scales.1`"
+        );
+        assert!(
+            config(syn::parse_quote! {
+                #[config(format = "toml", deny_non_finite)]
+                #[config(src = "ratio = nan")]
+                #[config(src = "ratio = 0.5")]
+                struct Limits;
+            })
+            .is_ok()
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn deny_null() {
+        assert_eq!(
+            error(syn::parse_quote! {
+                #[config(format = "json", deny_null)]
+                #[config(src = r#"{ "db": { "port": 5432, "proxy": null } }"#)]
+                struct MyConfig;
+            }),
+            "Null at `db.proxy`"
+        );
+        assert_eq!(
+            error(syn::parse_quote! {
+                #[config(format = "json", deny_null)]
+                #[config(src = r#"{ "hosts": ["a", null] }"#)]
+                struct MyConfig;
+            }),
+            "Null at `hosts.1`"
+        );
+        assert!(
+            config(syn::parse_quote! {
+                #[config(format = "json", deny_null)]
+                #[config(src = r#"{ "timeout": null }"#)]
+                #[config(src = r#"{ "timeout": 30 }"#)]
+                struct MyConfig;
+            })
+            .is_ok()
+        );
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn jiff_range() {
+        let datetime = |text: &str, offset: i16| {
+            let (date, time) = text.split_once('T').unwrap();
+            let date: Vec<_> = date.split('-').map(|n| n.parse().unwrap()).collect();
+            let time: Vec<u8> = time.split(':').map(|n| n.parse().unwrap()).collect();
+            let date = crate::value::Date {
+                year: date[0],
+                month: date[1] as u8,
+                day: date[2] as u8,
+            };
+            let time = crate::value::Time {
+                hour: time[0],
+                minute: time[1],
+                second: time[2],
+                nanosecond: 999_999_999,
+            };
+            Datetime::new(text.to_string(), Some(date), Some(time), Some(offset)).unwrap()
+        };
+        let tys = |datetime: Datetime| {
+            datetime
+                .jiff_convert_impls()
+                .into_iter()
+                .map(|convert_impl| {
+                    let ty = convert_impl.ty;
+                    quote::quote! { #ty }.to_string()
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(tys(datetime("9999-12-30T22:00:00", 0)).len(), 2);
+        assert_eq!(tys(datetime("9999-12-31T00:00:00", 120)).len(), 2);
+        assert!(tys(datetime("9999-12-30T22:00:01", 0)).is_empty());
+        assert!(tys(datetime("9999-12-31T23:00:00", 0)).is_empty());
+        assert!(tys(datetime("9999-12-30T21:00:01", -60)).is_empty());
+        assert_eq!(tys(datetime("0000-01-01T00:00:00", -1439)).len(), 2);
+        assert_eq!(tys(datetime("2000-02-29T12:00:00", 0)).len(), 2);
+    }
+
+    #[cfg(not(feature = "yaml"))]
+    #[test]
+    fn front_matter_without_feature() {
+        let error = source("front_matter!(\"---\\na: 1\\n---\\n\")")
+            .unwrap()
+            .format()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            "Front matter delimited by `---` requires feature `yaml`"
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use crate::value::{Map, Value};
+use std::error::Error;
+
+pub fn parse(bytes: &[u8]) -> Result<Value, Box<dyn Error>> {
+    let mut rest = bytes;
+    let value = ciborium::from_reader(&mut rest)?;
+    if !rest.is_empty() {
+        Err("Trailing bytes after CBOR item")?;
+    }
+    morph(value)
+}
+
+fn morph(value: ciborium::Value) -> Result<Value, Box<dyn Error>> {
+    Ok(match value {
+        ciborium::Value::Null => Value::Nil,
+        ciborium::Value::Bool(value) => Value::Boolean(value),
+        ciborium::Value::Integer(value) => {
+            let value = i128::from(value);
+            if value.is_negative() {
+                Value::NegInt(value)
+            } else {
+                Value::PosInt(value as u128)
+            }
+        }
+        ciborium::Value::Float(value) => Value::Float(value),
+        ciborium::Value::Text(value) => Value::String(value),
+        ciborium::Value::Bytes(value) => Value::Bytes(value),
+        ciborium::Value::Tag(tag @ (2 | 3), value) => bignum(tag == 3, *value)?,
+        // Other tags carry semantics the representation cannot express, so only the content is kept.
+        ciborium::Value::Tag(_, value) => morph(*value)?,
+        ciborium::Value::Array(value) => {
+            Value::Array(value.into_iter().map(morph).collect::<Result<_, _>>()?)
+        }
+        ciborium::Value::Map(value) => {
+            let mut table = Map::new();
+            for (key, value) in value {
+                let key = morph_key(key)?;
+                if table.contains_key(&key) {
+                    Err(format!("Duplicate CBOR map key `{key}`"))?;
+                }
+                table.insert(key, morph(value)?);
+            }
+            Value::Table(table)
+        }
+        _ => Err("Unsupported CBOR item")?,
+    })
+}
+
+// Bignums are big-endian magnitudes `n`, standing for `n` if positive and `-1 - n` if negative.
+fn bignum(is_negative: bool, value: ciborium::Value) -> Result<Value, Box<dyn Error>> {
+    let ciborium::Value::Bytes(bytes) = value else {
+        Err("CBOR bignums must be byte strings")?
+    };
+    let out_of_range = || "CBOR bignum is out of the 128-bit range";
+    let magnitude = bytes
+        .iter()
+        .try_fold(0u128, |magnitude, &byte| {
+            magnitude.checked_mul(256)?.checked_add(byte.into())
+        })
+        .ok_or_else(out_of_range)?;
+    Ok(if !is_negative {
+        Value::PosInt(magnitude)
+    } else {
+        Value::NegInt(
+            i128::try_from(magnitude)
+                .ok()
+                .and_then(|magnitude| (-1i128).checked_sub(magnitude))
+                .ok_or_else(out_of_range)?,
+        )
+    })
+}
+
+fn morph_key(key: ciborium::Value) -> Result<String, Box<dyn Error>> {
+    match morph(key)? {
+        Value::Boolean(key) => Ok(key.to_string()),
+        Value::PosInt(key) => Ok(key.to_string()),
+        Value::NegInt(key) => Ok(key.to_string()),
+        Value::String(key) => Ok(key),
+        _ => Err("CBOR map keys must be strings, integers or booleans".into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ciborium::Value as Cbor;
+
+    fn encode(value: Cbor) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&value, &mut bytes).unwrap();
+        bytes
+    }
+
+    fn text(s: &str) -> Cbor {
+        Cbor::Text(s.to_string())
+    }
+
+    #[test]
+    fn items() {
+        let bytes = encode(Cbor::Map(vec![
+            (text("a"), Cbor::Integer(1.into())),
+            (text("b"), Cbor::Integer((-2).into())),
+            (text("c"), Cbor::Float(1.5)),
+            (text("d"), Cbor::Bytes(vec![1, 2])),
+            (text("e"), Cbor::Array(vec![Cbor::Bool(true), Cbor::Null])),
+            (Cbor::Integer(7.into()), text("seven")),
+            (
+                text("f"),
+                Cbor::Tag(0, Box::new(text("2024-01-01T00:00:00Z"))),
+            ),
+        ]));
+        let Value::Table(table) = parse(&bytes).unwrap() else {
+            panic!()
+        };
+        assert_eq!(table["a"], Value::PosInt(1));
+        assert_eq!(table["b"], Value::NegInt(-2));
+        assert_eq!(table["c"], Value::Float(1.5));
+        assert_eq!(table["d"], Value::Bytes(vec![1, 2]));
+        assert_eq!(
+            table["e"],
+            Value::Array(vec![Value::Boolean(true), Value::Nil])
+        );
+        assert_eq!(table["7"], Value::String("seven".to_string()));
+        assert_eq!(
+            table["f"],
+            Value::String("2024-01-01T00:00:00Z".to_string())
+        );
+    }
+
+    #[test]
+    fn bignums() {
+        let bignum = |tag, bytes: &[u8]| {
+            parse(&encode(Cbor::Tag(
+                tag,
+                Box::new(Cbor::Bytes(bytes.to_vec())),
+            )))
+        };
+        assert_eq!(
+            bignum(2, &[1, 0, 0, 0, 0, 0, 0, 0, 0]).unwrap(),
+            Value::PosInt(1 << 64)
+        );
+        assert_eq!(
+            bignum(3, &[1, 0, 0, 0, 0, 0, 0, 0, 0]).unwrap(),
+            Value::NegInt(-(1 << 64) - 1)
+        );
+        assert_eq!(bignum(2, &[0xff; 16]).unwrap(), Value::PosInt(u128::MAX));
+        assert_eq!(bignum(3, &[0x7f; 1]).unwrap(), Value::NegInt(-128));
+        assert_eq!(bignum(2, &[]).unwrap(), Value::PosInt(0));
+        assert!(bignum(2, &[1; 17]).is_err());
+        assert!(bignum(3, &[0x80; 16]).is_err());
+        assert!(parse(&encode(Cbor::Tag(2, Box::new(text("1"))))).is_err());
+    }
+
+    #[test]
+    fn colliding_keys() {
+        let bytes = encode(Cbor::Map(vec![
+            (Cbor::Integer(1.into()), Cbor::Null),
+            (text("1"), Cbor::Null),
+        ]));
+        let error = parse(&bytes).unwrap_err();
+        assert_eq!(error.to_string(), "Duplicate CBOR map key `1`");
+        let bytes = encode(Cbor::Map(vec![
+            (Cbor::Bool(true), Cbor::Null),
+            (text("true"), Cbor::Null),
+        ]));
+        assert!(parse(&bytes).is_err());
+    }
+
+    #[test]
+    fn trailing_bytes() {
+        let mut bytes = encode(Cbor::Null);
+        bytes.push(0);
+        assert!(parse(&bytes).is_err());
+    }
+}
@@ -0,0 +1,202 @@
+use crate::value::Value;
+use darling::FromMeta;
+use std::error::Error;
+
+#[derive(Debug, FromMeta, PartialEq)]
+#[darling(default, from_none = || Some(Self::default()))]
+pub struct Options {
+    delimiter: char,
+}
+
+impl Options {
+    pub fn tsv() -> Self {
+        Self { delimiter: '\t' }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self { delimiter: ',' }
+    }
+}
+
+pub fn parse(s: &str, options: &Options) -> Result<Value, Box<dyn Error>> {
+    let delimiter = u8::try_from(options.delimiter)
+        .ok()
+        .filter(u8::is_ascii)
+        .ok_or("The delimiter must be an ASCII character")?;
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .from_reader(s.as_bytes());
+    let headers = reader.headers()?.clone();
+    if let Some(header) = headers
+        .iter()
+        .enumerate()
+        .find_map(|(index, header)| headers.iter().skip(index + 1).find(|h| h == &header))
+    {
+        Err(format!("Duplicate header `{header}`"))?;
+    }
+    // Each row is a table keyed by the header.
+    Ok(Value::Array(
+        reader
+            .records()
+            .enumerate()
+            .map(|(index, record)| {
+                Ok(Value::Table(
+                    headers
+                        .iter()
+                        .zip(record?.iter())
+                        .map(|(header, cell)| {
+                            let value = morph(cell).map_err(|e| {
+                                format!("{e} in column `{header}` of row {}", index + 1)
+                            })?;
+                            Ok((header.to_string(), value))
+                        })
+                        .collect::<Result<_, String>>()?,
+                ))
+            })
+            .collect::<Result<_, Box<dyn Error>>>()?,
+    ))
+}
+
+fn morph(cell: &str) -> Result<Value, String> {
+    Ok(if cell.is_empty() {
+        Value::Nil
+    } else if cell.eq_ignore_ascii_case("true") {
+        Value::Boolean(true)
+    } else if cell.eq_ignore_ascii_case("false") {
+        Value::Boolean(false)
+    } else if !is_number(cell) {
+        Value::String(cell.to_string())
+    } else if let Ok(value) = cell.parse() {
+        Value::PosInt(value)
+    } else if let Ok(value) = cell.parse::<i128>() {
+        // `-0` is zero like `0`.
+        if value == 0 {
+            Value::PosInt(0)
+        } else {
+            Value::NegInt(value)
+        }
+    } else if !cell.contains(['.', 'e', 'E']) {
+        Err(format!("Integer `{cell}` is out of the 128-bit range"))?
+    } else {
+        cell.parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .map(Value::Float)
+            .ok_or_else(|| format!("Number `{cell}` is out of range"))?
+    })
+}
+
+// Only numbers in canonical form are typed, so that cells like `007` or `+1` stay strings.
+fn is_number(cell: &str) -> bool {
+    let digits = cell.strip_prefix('-').unwrap_or(cell);
+    let (mantissa, exponent) = digits
+        .split_once(['e', 'E'])
+        .map_or((digits, None), |(mantissa, exponent)| {
+            (mantissa, Some(exponent))
+        });
+    let (integer, fraction) = mantissa
+        .split_once('.')
+        .map_or((mantissa, None), |(integer, fraction)| {
+            (integer, Some(fraction))
+        });
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    is_digits(integer)
+        && (integer == "0" || !integer.starts_with('0'))
+        && fraction.is_none_or(is_digits)
+        && exponent
+            .is_none_or(|exponent| is_digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(s: &str, options: &Options) -> Vec<Value> {
+        let Value::Array(rows) = parse(s, options).unwrap() else {
+            panic!()
+        };
+        rows
+    }
+
+    fn row(pairs: &[(&str, Value)]) -> Value {
+        Value::Table(
+            pairs
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn headers() {
+        assert_eq!(
+            rows("name,port\nweb,80\napi,81", &Options::default()),
+            [
+                row(&[
+                    ("name", Value::String("web".to_string())),
+                    ("port", Value::PosInt(80))
+                ]),
+                row(&[
+                    ("name", Value::String("api".to_string())),
+                    ("port", Value::PosInt(81))
+                ]),
+            ]
+        );
+        assert!(rows("name,port", &Options::default()).is_empty());
+        let error = parse("a,b,a\n1,2,3", &Options::default()).unwrap_err();
+        assert_eq!(error.to_string(), "Duplicate header `a`");
+        assert!(parse("a,b\n1,2,3", &Options::default()).is_err());
+    }
+
+    #[test]
+    fn delimiters() {
+        let expected = [row(&[("a", Value::PosInt(1)), ("b", Value::PosInt(2))])];
+        assert_eq!(rows("a;b\n1;2", &Options { delimiter: ';' }), expected);
+        assert_eq!(rows("a\tb\n1\t2", &Options::tsv()), expected);
+        assert!(parse("a\n1", &Options { delimiter: 'é' }).is_err());
+    }
+
+    #[test]
+    fn inference() {
+        for (cell, value) in [
+            ("", Value::Nil),
+            ("TRUE", Value::Boolean(true)),
+            ("false", Value::Boolean(false)),
+            ("42", Value::PosInt(42)),
+            ("-42", Value::NegInt(-42)),
+            ("-0", Value::PosInt(0)),
+            ("1.5", Value::Float(1.5)),
+            ("-2.5e-3", Value::Float(-2.5e-3)),
+            ("007", Value::String("007".to_string())),
+            ("+1", Value::String("+1".to_string())),
+            ("1.", Value::String("1.".to_string())),
+            ("inf", Value::String("inf".to_string())),
+            ("web", Value::String("web".to_string())),
+            (
+                "340282366920938463463374607431768211455",
+                Value::PosInt(u128::MAX),
+            ),
+        ] {
+            assert_eq!(morph(cell), Ok(value), "{cell}");
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        for cell in [
+            "340282366920938463463374607431768211456",
+            "-170141183460469231731687303715884105729",
+            "1e400",
+            "-1e400",
+        ] {
+            assert!(morph(cell).is_err(), "{cell}");
+        }
+        let error = parse("a,b\n1,1e400", &Options::default()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Number `1e400` is out of range in column `b` of row 1"
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use crate::value::{Map, Value};
+use darling::FromMeta;
+use std::error::Error;
+
+#[derive(Debug, Default, FromMeta, PartialEq)]
+#[darling(default, from_none = || Some(Self::default()))]
+pub struct Options {
+    separator: Option<String>,
+}
+
+// A hand-written parser, as there is no formal specification for dotenv files.
+// `${VAR}` and `$VAR` only refer to variables defined earlier in the same file,
+// so the result never depends on the environment of the compiler.
+pub fn parse(s: &str, options: &Options) -> Result<Value, Box<dyn Error>> {
+    let mut parser = Parser { src: s, pos: 0 };
+    let mut variables: Vec<(String, String)> = Vec::new();
+    while let Some((key, value)) = parser.line(&variables)? {
+        variables.retain(|(k, _)| k != &key);
+        variables.push((key, value));
+    }
+    let mut root = Map::new();
+    for (key, value) in variables {
+        let mut names: Vec<_> = match &options.separator {
+            Some(separator) => key.split(separator.as_str()).collect(),
+            None => vec![key.as_str()],
+        };
+        let name = names.pop().unwrap(); // Never fails.
+        let mut table = &mut root;
+        for name in names {
+            table = match table
+                .entry(name.to_string())
+                .or_insert_with(|| Value::Table(Map::new()))
+            {
+                Value::Table(table) => table,
+                _ => Err(format!("Key `{key}` conflicts with another key"))?,
+            };
+        }
+        if table
+            .insert(name.to_string(), Value::String(value))
+            .is_some()
+        {
+            Err(format!("Key `{key}` conflicts with another key"))?;
+        }
+    }
+    Ok(Value::Table(root))
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, msg: &str) -> Box<dyn Error> {
+        let consumed = &self.src[..self.pos];
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed.chars().rev().take_while(|&c| c != '\n').count() + 1;
+        format!("{msg} at line {line} column {column}").into()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        let matched = self.src[self.pos..].starts_with(s);
+        if matched {
+            self.pos += s.len();
+        }
+        matched
+    }
+
+    fn skip_blanks(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t')) {
+            self.next();
+        }
+    }
+
+    fn skip_line(&mut self) {
+        while !matches!(self.next(), None | Some('\n')) {}
+    }
+
+    fn line(
+        &mut self,
+        variables: &[(String, String)],
+    ) -> Result<Option<(String, String)>, Box<dyn Error>> {
+        loop {
+            self.skip_blanks();
+            match self.peek() {
+                None => return Ok(None),
+                Some('\r' | '\n' | '#') => self.skip_line(),
+                Some(_) => break,
+            }
+        }
+        if self.eat("export") && !matches!(self.peek(), Some(' ' | '\t')) {
+            self.pos -= "export".len();
+        }
+        self.skip_blanks();
+        let key = self.key()?;
+        self.skip_blanks();
+        if !self.eat("=") {
+            return Err(self.error("expected `=`"));
+        }
+        self.skip_blanks();
+        let value = match self.peek() {
+            Some('\'') => self.single_quoted()?,
+            Some('"') => self.double_quoted(variables)?,
+            _ => self.unquoted(variables)?,
+        };
+        self.skip_blanks();
+        match self.peek() {
+            None | Some('\r' | '\n' | '#') => self.skip_line(),
+            Some(_) => return Err(self.error("unexpected characters after value")),
+        }
+        Ok(Some((key, value)))
+    }
+
+    fn key(&mut self) -> Result<String, Box<dyn Error>> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+        {
+            self.next();
+        }
+        let key = &self.src[start..self.pos];
+        if key.is_empty() || key.starts_with(|c: char| c.is_ascii_digit()) {
+            self.pos = start;
+            return Err(self.error("expected key"));
+        }
+        Ok(key.to_string())
+    }
+
+    fn single_quoted(&mut self) -> Result<String, Box<dyn Error>> {
+        self.next();
+        let start = self.pos;
+        loop {
+            match self.next() {
+                Some('\'') => return Ok(self.src[start..self.pos - 1].to_string()),
+                Some(_) => {}
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn double_quoted(&mut self, variables: &[(String, String)]) -> Result<String, Box<dyn Error>> {
+        self.next();
+        let mut value = String::new();
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.next();
+                    return Ok(value);
+                }
+                Some('\\') => {
+                    self.next();
+                    match self.next() {
+                        Some('n') => value.push('\n'),
+                        Some('r') => value.push('\r'),
+                        Some('t') => value.push('\t'),
+                        Some(c @ ('"' | '\\' | '$')) => value.push(c),
+                        Some(c) => {
+                            value.push('\\');
+                            value.push(c);
+                        }
+                        None => return Err(self.error("unterminated string")),
+                    }
+                }
+                Some('$') => value.push_str(self.reference(variables)?),
+                Some(c) => {
+                    self.next();
+                    value.push(c);
+                }
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn unquoted(&mut self, variables: &[(String, String)]) -> Result<String, Box<dyn Error>> {
+        let mut value = String::new();
+        loop {
+            match self.peek() {
+                None | Some('\r' | '\n') => break,
+                // A `#` preceded by whitespace starts a comment.
+                Some('#') if value.is_empty() || value.ends_with([' ', '\t']) => break,
+                Some('$') => value.push_str(self.reference(variables)?),
+                Some(c) => {
+                    self.next();
+                    value.push(c);
+                }
+            }
+        }
+        Ok(value.trim_end().to_string())
+    }
+
+    // A `$` starting no reference, like in `$5`, is kept literally.
+    fn reference<'v>(
+        &mut self,
+        variables: &'v [(String, String)],
+    ) -> Result<&'v str, Box<dyn Error>> {
+        self.next();
+        let braced = self.eat("{");
+        if !braced
+            && !self
+                .peek()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        {
+            return Ok("$");
+        }
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            self.next();
+        }
+        let name = &self.src[start..self.pos];
+        if braced && !self.eat("}") {
+            return Err(self.error("expected `}`"));
+        }
+        if name.is_empty() {
+            return Err(self.error("expected variable name"));
+        }
+        variables
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+            .ok_or_else(|| self.error(&format!("undefined variable `{name}`")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(s: &str) -> Map<String, Value> {
+        match parse(s, &Options::default()).unwrap() {
+            Value::Table(table) => table,
+            _ => panic!("not a table"),
+        }
+    }
+
+    fn string(s: &str) -> Value {
+        Value::String(s.to_string())
+    }
+
+    #[test]
+    fn quoting() {
+        let table = table(
+            r#"
+            PLAIN = some value  # comment
+            HASH = a#b
+            SINGLE = 'raw \n $PLAIN'
+            DOUBLE = "line\n\"quoted\" \$PLAIN # kept"
+            MULTI = "first
+            second"
+            EMPTY =
+            "#,
+        );
+        assert_eq!(table["PLAIN"], string("some value"));
+        assert_eq!(table["HASH"], string("a#b"));
+        assert_eq!(table["SINGLE"], string("raw \\n $PLAIN"));
+        assert_eq!(table["DOUBLE"], string("line\n\"quoted\" $PLAIN # kept"));
+        assert_eq!(table["MULTI"], string("first\n            second"));
+        assert_eq!(table["EMPTY"], string(""));
+    }
+
+    #[test]
+    fn export() {
+        let table = table("export A=1\nexport\tB=2\nexported=3");
+        assert_eq!(table["A"], string("1"));
+        assert_eq!(table["B"], string("2"));
+        assert_eq!(table["exported"], string("3"));
+    }
+
+    #[test]
+    fn references() {
+        let table = table(
+            r#"
+            HOST=localhost
+            URL=http://$HOST:${HOST}
+            PRICE=$5 and $
+            QUOTED="${HOST}/$HOST"
+            HOST=example.com
+            "#,
+        );
+        assert_eq!(table["URL"], string("http://localhost:localhost"));
+        assert_eq!(table["PRICE"], string("$5 and $"));
+        assert_eq!(table["QUOTED"], string("localhost/localhost"));
+        assert_eq!(table["HOST"], string("example.com"));
+        assert!(parse("URL=${PORT}\nPORT=80", &Options::default()).is_err());
+        assert!(parse("A=${B", &Options::default()).is_err());
+        assert!(parse("A=$UNDEFINED", &Options::default()).is_err());
+    }
+
+    #[test]
+    fn separator() {
+        let options = Options {
+            separator: Some("__".to_string()),
+        };
+        let Value::Table(table) = parse("DB__HOST=h\nDB__PORT=1", &options).unwrap() else {
+            panic!()
+        };
+        let Value::Table(db) = &table["DB"] else {
+            panic!()
+        };
+        assert_eq!(db["HOST"], string("h"));
+        assert!(parse("DB=1\nDB__HOST=h", &options).is_err());
+    }
+
+    #[test]
+    fn invalid_lines() {
+        assert!(parse("NO_EQUALS", &Options::default()).is_err());
+        assert!(parse("1KEY=1", &Options::default()).is_err());
+        assert!(parse("A='unterminated", &Options::default()).is_err());
+        assert!(parse("A=\"x\" trailing", &Options::default()).is_err());
+    }
+}
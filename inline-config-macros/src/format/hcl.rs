@@ -0,0 +1,313 @@
+use crate::value::{Map, Value};
+use darling::FromMeta;
+use std::collections::HashSet;
+use std::error::Error;
+
+#[derive(Debug, Default, FromMeta, PartialEq)]
+#[darling(default, from_none = || Some(Self::default()))]
+pub struct Options {
+    #[darling(multiple)]
+    array: Vec<String>,
+}
+
+pub fn parse(s: &str, options: &Options) -> Result<Value, Box<dyn Error>> {
+    let body: hcl::edit::structure::Body = s.parse()?;
+    let mut integers = Integers(Ok(()));
+    hcl::edit::visit::Visit::visit_body(&mut integers, &body);
+    integers.0?;
+    morph_body(body.into(), options)
+}
+
+// The parser rejects integers beyond 64 bits, except negative ones, which it silently wraps.
+// Their original text tells them apart.
+struct Integers(Result<(), String>);
+
+impl hcl::edit::visit::Visit for Integers {
+    fn visit_number(&mut self, node: &hcl::edit::Formatted<hcl::Number>) {
+        let Some(text) = node
+            .as_repr()
+            .map(|repr| repr.replace(char::is_whitespace, ""))
+        else {
+            return;
+        };
+        if self.0.is_ok()
+            && !text.contains(['.', 'e', 'E'])
+            && text.parse::<i128>().ok() != node.as_i64().map(i128::from)
+            && text.parse::<i128>().ok() != node.as_u64().map(i128::from)
+        {
+            self.0 = Err(format!("Integer `{text}` is out of the 64-bit range"));
+        }
+    }
+}
+
+// Labeled blocks like `service "http" { ... }` nest by their labels, and never form arrays.
+// Repeated unlabeled blocks form an array, as repeated XML elements do, and so does a single one
+// if its name is listed in `array`.
+fn morph_body(body: hcl::Body, options: &Options) -> Result<Value, Box<dyn Error>> {
+    let mut root = Map::new();
+    // Tables formed by block names and labels, which other blocks may share.
+    let mut label_tables = HashSet::new();
+    // Names of unlabeled blocks.
+    let mut blocks = HashSet::new();
+    for structure in body {
+        match structure {
+            hcl::Structure::Attribute(attribute) => {
+                let key = attribute.key.into_inner();
+                let value =
+                    morph(attribute.expr).map_err(|e| format!("{e} in attribute `{key}`"))?;
+                if root.insert(key.clone(), value).is_some() {
+                    Err(format!(
+                        "Attribute `{key}` conflicts with another attribute or block"
+                    ))?;
+                }
+            }
+            hcl::Structure::Block(block) => {
+                let is_labeled = !block.labels.is_empty();
+                let mut names = std::iter::once(block.identifier.into_inner())
+                    .chain(block.labels.into_iter().map(hcl::BlockLabel::into_inner))
+                    .collect::<Vec<_>>();
+                let path = names.join(".");
+                let conflict =
+                    || format!("Block `{path}` conflicts with another attribute or block");
+                let name = names.pop().unwrap(); // Never fails.
+                let mut table = &mut root;
+                for (depth, label) in names.iter().enumerate() {
+                    let prefix = names[..=depth].to_vec();
+                    if !table.contains_key(label) {
+                        table.insert(label.clone(), Value::Table(Map::new()));
+                        label_tables.insert(prefix.clone());
+                    }
+                    table = match table.get_mut(label) {
+                        Some(Value::Table(table)) if label_tables.contains(&prefix) => table,
+                        _ => Err(conflict())?,
+                    };
+                }
+                let value = morph_body(block.body, options)?;
+                if is_labeled {
+                    if table.contains_key(&name) {
+                        Err(conflict())?;
+                    }
+                    table.insert(name, value);
+                } else if let Some(existing) =
+                    table.get_mut(&name).filter(|_| blocks.contains(&name))
+                {
+                    // Block bodies are tables, so an array is only formed by repetition.
+                    match existing {
+                        Value::Array(values) => values.push(value),
+                        first => *first = Value::Array(vec![first.clone(), value]),
+                    }
+                } else if table.contains_key(&name) {
+                    Err(conflict())?;
+                } else {
+                    blocks.insert(name.clone());
+                    let value = if options.array.contains(&name) {
+                        Value::Array(vec![value])
+                    } else {
+                        value
+                    };
+                    table.insert(name, value);
+                }
+            }
+        }
+    }
+    Ok(Value::Table(root))
+}
+
+fn morph(expr: hcl::Expression) -> Result<Value, Box<dyn Error>> {
+    Ok(match expr {
+        hcl::Expression::Null => Value::Nil,
+        hcl::Expression::Bool(value) => Value::Boolean(value),
+        hcl::Expression::Number(value) => {
+            if let Some(value) = value.as_u64() {
+                Value::PosInt(value.into())
+            } else if let Some(value) = value.as_i64() {
+                Value::NegInt(value.into())
+            } else {
+                Value::Float(value.as_f64().unwrap()) // Never fails.
+            }
+        }
+        hcl::Expression::String(value) => Value::String(value),
+        hcl::Expression::Array(value) => {
+            Value::Array(value.into_iter().map(morph).collect::<Result<_, _>>()?)
+        }
+        hcl::Expression::Object(value) => Value::Table(
+            value
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = match key {
+                        hcl::ObjectKey::Identifier(key) => key.into_inner(),
+                        hcl::ObjectKey::Expression(key) => match morph(key)? {
+                            Value::String(key) => key,
+                            _ => Err("Object keys must be identifiers or strings")?,
+                        },
+                        _ => Err("Object keys must be identifiers or strings")?,
+                    };
+                    morph(value).map(|value| (key, value))
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        // Only templates without interpolations or directives are literal.
+        hcl::Expression::TemplateExpr(value) => Value::String(
+            hcl::Template::from_expr(&value)?
+                .elements()
+                .iter()
+                .map(|element| match element {
+                    hcl::template::Element::Literal(literal) => Ok(literal.as_str()),
+                    _ => Err("Template interpolations and directives are not supported"),
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        hcl::Expression::Parenthesis(value) => morph(*value)?,
+        _ => Err("Only literal expressions are supported")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(s: &str, options: &Options) -> Map<String, Value> {
+        match parse(s, options).unwrap() {
+            Value::Table(table) => table,
+            _ => panic!("not a table"),
+        }
+    }
+
+    fn get<'a>(value: &'a Value, key: &str) -> &'a Value {
+        match value {
+            Value::Table(table) => &table[key],
+            _ => panic!("not a table"),
+        }
+    }
+
+    #[test]
+    fn labeled_blocks_are_keyed_by_labels() {
+        let table = table(
+            r#"
+            service "http" "web" { port = 80 }
+            service "http" "api" { port = 81 }
+            service "grpc" "api" { port = 82 }
+            "#,
+            &Options::default(),
+        );
+        let service = &table["service"];
+        assert_eq!(
+            get(get(get(service, "http"), "web"), "port"),
+            &Value::PosInt(80)
+        );
+        assert_eq!(
+            get(get(get(service, "http"), "api"), "port"),
+            &Value::PosInt(81)
+        );
+        assert_eq!(
+            get(get(get(service, "grpc"), "api"), "port"),
+            &Value::PosInt(82)
+        );
+    }
+
+    #[test]
+    fn single_labeled_block_is_a_table() {
+        let table = table(r#"service "a" { port = 80 }"#, &Options::default());
+        assert_eq!(get(get(&table["service"], "a"), "port"), &Value::PosInt(80));
+    }
+
+    #[test]
+    fn unlabeled_blocks() {
+        let table = table("server { port = 80 }", &Options::default());
+        assert_eq!(get(&table["server"], "port"), &Value::PosInt(80));
+        let table = self::table(
+            "rule { allow = true }\nrule { allow = false }\nrule {}",
+            &Options::default(),
+        );
+        let Value::Array(rules) = &table["rule"] else {
+            panic!()
+        };
+        assert_eq!(rules.len(), 3);
+        assert_eq!(get(&rules[1], "allow"), &Value::Boolean(false));
+        assert!(parse("rule {}\nrule = 1", &Options::default()).is_err());
+        assert!(parse("rule = 1\nrule {}", &Options::default()).is_err());
+    }
+
+    #[test]
+    fn array_option() {
+        let options = Options {
+            array: vec!["rule".to_string()],
+        };
+        let table = self::table("rule { allow = true }", &options);
+        assert_eq!(
+            table["rule"],
+            Value::Array(vec![Value::Table(
+                [("allow".to_string(), Value::Boolean(true))]
+                    .into_iter()
+                    .collect()
+            )])
+        );
+        let table = self::table("rule { allow = true }\nrule { allow = false }", &options);
+        let Value::Array(rules) = &table["rule"] else {
+            panic!()
+        };
+        assert_eq!(rules.len(), 2);
+        assert!(parse("rule = []\nrule {}", &options).is_err());
+        assert!(parse("rule {}\nrule = []", &options).is_err());
+    }
+
+    #[test]
+    fn conflicts() {
+        let options = Options::default();
+        assert!(parse(r#"service "a" {}"#.repeat(2).as_str(), &options).is_err());
+        assert!(parse("service = { a = {} }\nservice \"b\" {}", &options).is_err());
+        assert!(parse("service = { a = 1 }\nservice {}", &options).is_err());
+        assert!(parse("service {}\nservice \"a\" {}", &options).is_err());
+        assert!(parse("service \"a\" {}\nservice {}", &options).is_err());
+        assert!(parse("service \"a\" {}\nservice \"a\" \"b\" {}", &options).is_err());
+        assert!(parse("a = 1\na = 2", &options).is_err());
+    }
+
+    #[test]
+    fn expressions() {
+        let table = table(
+            r#"
+            a = null
+            b = [1, -2, 1.5]
+            c = { "key-1" = "v", key2 = ("x") }
+            d = <<EOT
+            heredoc
+            EOT
+            "#,
+            &Options::default(),
+        );
+        assert_eq!(table["a"], Value::Nil);
+        assert_eq!(
+            table["b"],
+            Value::Array(vec![Value::PosInt(1), Value::NegInt(-2), Value::Float(1.5)])
+        );
+        assert_eq!(get(&table["c"], "key-1"), &Value::String("v".to_string()));
+        assert_eq!(get(&table["c"], "key2"), &Value::String("x".to_string()));
+        assert_eq!(
+            table["d"],
+            Value::String("            heredoc\n".to_string())
+        );
+        assert!(parse("a = var.b", &Options::default()).is_err());
+        assert!(parse("a = \"${b}\"", &Options::default()).is_err());
+        assert!(parse("a = 1 + 2", &Options::default()).is_err());
+    }
+
+    #[test]
+    fn integers_beyond_64_bits() {
+        let table = table(
+            "max = 18446744073709551615\nmin = -9223372036854775808\nfloat = 1.5",
+            &Options::default(),
+        );
+        assert_eq!(table["max"], Value::PosInt(u64::MAX.into()));
+        assert_eq!(table["min"], Value::NegInt(i64::MIN.into()));
+        assert_eq!(table["float"], Value::Float(1.5));
+        for s in [
+            "a = 18446744073709551616",
+            "a = -9223372036854775809",
+            "a = -10000000000000000000",
+            "a = [-10000000000000000000]",
+        ] {
+            assert!(parse(s, &Options::default()).is_err(), "{s}");
+        }
+    }
+}
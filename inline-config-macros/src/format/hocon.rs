@@ -0,0 +1,931 @@
+use crate::value::{Map, Value};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+// A hand-written parser following the HOCON specification.
+// https://github.com/lightbend/config/blob/main/HOCON.md
+// Substitutions missing from the config fall back to environment variables of the compiler.
+pub fn parse(s: &str, dir: Option<&Path>) -> Result<Value, Box<dyn Error>> {
+    let mut parser = Parser {
+        src: s,
+        pos: 0,
+        dir,
+        prefix: Vec::new(),
+        includes: Vec::new(),
+    };
+    let root = Node::Object(parser.document()?);
+    let mut resolver = Resolver {
+        root: &root,
+        stack: Vec::new(),
+    };
+    Ok(resolver
+        .merge(vec![Layer::Node(&root)])?
+        .unwrap_or(Value::Table(Map::new())))
+}
+
+// A value before substitutions are resolved and duplicate keys are merged.
+enum Node {
+    // A scalar and its original text, which is used when concatenated into a string.
+    Scalar(Value, String),
+    Space(String),
+    Array(Vec<Node>),
+    Object(Vec<(String, Node)>),
+    Substitution {
+        path: Vec<String>,
+        optional: bool,
+        // The path of the include statement, which is tried before the root.
+        prefix: Vec<String>,
+        // Whether to fall back to the environment variable named by the path.
+        env: bool,
+    },
+    Concat(Vec<Node>),
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+    dir: Option<&'a Path>,
+    prefix: Vec<String>,
+    includes: Vec<PathBuf>,
+}
+
+impl Parser<'_> {
+    fn error(&self, msg: &str) -> Box<dyn Error> {
+        let consumed = &self.src[..self.pos];
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed.chars().rev().take_while(|&c| c != '\n').count() + 1;
+        format!("{msg} at line {line} column {column}").into()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        let matched = self.src[self.pos..].starts_with(s);
+        if matched {
+            self.pos += s.len();
+        }
+        matched
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), Box<dyn Error>> {
+        if self.next() == Some(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{c}`")))
+        }
+    }
+
+    fn at_blank(&self) -> bool {
+        self.peek().is_some_and(|c| c.is_whitespace() && c != '\n')
+    }
+
+    fn at_comment(&self) -> bool {
+        self.src[self.pos..].starts_with('#') || self.src[self.pos..].starts_with("//")
+    }
+
+    fn at_unquoted(&self) -> bool {
+        self.peek().is_some_and(|c| {
+            !c.is_whitespace() && !"$\"{}[]:=,+#`^?!@*&\\".contains(c) && !self.at_comment()
+        })
+    }
+
+    // Skips whitespace and comments within a line.
+    fn skip_blanks(&mut self) {
+        while self.at_blank() {
+            self.next();
+        }
+        if self.at_comment() {
+            while !matches!(self.peek(), None | Some('\n')) {
+                self.next();
+            }
+        }
+    }
+
+    // Skips whitespace and comments, including newlines.
+    fn skip_space(&mut self) {
+        loop {
+            self.skip_blanks();
+            if !self.eat("\n") {
+                return;
+            }
+        }
+    }
+
+    fn document(&mut self) -> Result<Vec<(String, Node)>, Box<dyn Error>> {
+        let prefix = self.prefix.clone();
+        self.skip_space();
+        let fields = if self.eat("{") {
+            self.fields(&prefix, true)?
+        } else {
+            self.fields(&prefix, false)?
+        };
+        self.skip_space();
+        if self.peek().is_some() {
+            return Err(self.error("trailing characters"));
+        }
+        Ok(fields)
+    }
+
+    fn fields(
+        &mut self,
+        path: &[String],
+        braced: bool,
+    ) -> Result<Vec<(String, Node)>, Box<dyn Error>> {
+        let mut fields = Vec::new();
+        loop {
+            self.skip_space();
+            match self.peek() {
+                None if braced => return Err(self.error("expected `}`")),
+                None => return Ok(fields),
+                Some('}') if braced => {
+                    self.next();
+                    return Ok(fields);
+                }
+                _ => {}
+            }
+            if self.include_keyword() {
+                fields.extend(self.include(path)?);
+            } else {
+                fields.push(self.field(path)?);
+            }
+            // Fields are separated by commas or newlines.
+            self.skip_blanks();
+            match self.peek() {
+                Some(',') => {
+                    self.next();
+                }
+                None | Some('\n') => {}
+                Some('}') if braced => {}
+                _ => return Err(self.error("expected `,` or newline")),
+            }
+        }
+    }
+
+    fn field(&mut self, path: &[String]) -> Result<(String, Node), Box<dyn Error>> {
+        let keys = self.key()?;
+        let path = [path, &keys].concat();
+        self.skip_blanks();
+        let value = if self.eat("+=") {
+            // `a += b` is a shorthand for `a = ${?a} [b]`,
+            // which never takes an environment variable like `PATH` as the previous value.
+            self.skip_space();
+            Node::Concat(vec![
+                Node::Substitution {
+                    path: path.clone(),
+                    optional: true,
+                    prefix: Vec::new(),
+                    env: false,
+                },
+                Node::Array(vec![self.value(&path)?]),
+            ])
+        } else if self.eat(":") || self.eat("=") {
+            self.skip_space();
+            self.value(&path)?
+        } else if self.peek() == Some('{') {
+            self.value(&path)?
+        } else {
+            return Err(self.error("expected `:`, `=` or `{`"));
+        };
+        // A path expression expands to nested objects.
+        let (first, rest) = keys.split_first().unwrap(); // Never fails.
+        let value = rest
+            .iter()
+            .rev()
+            .fold(value, |value, key| Node::Object(vec![(key.clone(), value)]));
+        Ok((first.clone(), value))
+    }
+
+    fn key(&mut self) -> Result<Vec<String>, Box<dyn Error>> {
+        let start = self.pos;
+        let mut keys = Vec::new();
+        let mut key = String::new();
+        loop {
+            match self.peek() {
+                Some('"') => key.push_str(&self.string()?),
+                Some('.') => {
+                    self.next();
+                    keys.push(std::mem::take(&mut key));
+                }
+                Some(c) if self.at_unquoted() => {
+                    self.next();
+                    key.push(c);
+                }
+                _ if self.at_blank() => {
+                    // Whitespace is only part of the key between other parts.
+                    let blank_start = self.pos;
+                    while self.at_blank() {
+                        self.next();
+                    }
+                    if self.peek() == Some('"') || self.at_unquoted() {
+                        key.push_str(&self.src[blank_start..self.pos]);
+                    } else {
+                        self.pos = blank_start;
+                        break;
+                    }
+                }
+                _ => break,
+            }
+        }
+        if self.pos == start {
+            return Err(self.error("expected key"));
+        }
+        keys.push(key);
+        Ok(keys)
+    }
+
+    fn value(&mut self, path: &[String]) -> Result<Node, Box<dyn Error>> {
+        // Adjacent values on the same line are concatenated.
+        let mut pieces = Vec::new();
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    let s = self.string()?;
+                    pieces.push(Node::Scalar(Value::String(s.clone()), s));
+                }
+                Some('[') => pieces.push(self.array()?),
+                Some('{') => {
+                    self.next();
+                    pieces.push(Node::Object(self.fields(path, true)?));
+                }
+                Some('$') if self.src[self.pos..].starts_with("${") => {
+                    pieces.push(self.substitution()?)
+                }
+                _ if self.at_blank() => {
+                    let start = self.pos;
+                    while self.at_blank() {
+                        self.next();
+                    }
+                    pieces.push(Node::Space(self.src[start..self.pos].to_string()));
+                }
+                _ if self.at_unquoted() => pieces.push(self.unquoted()?),
+                _ => break,
+            }
+        }
+        while let Some(Node::Space(_)) = pieces.last() {
+            pieces.pop();
+        }
+        match pieces.len() {
+            0 => Err(self.error("expected value")),
+            1 => Ok(pieces.pop().unwrap()), // Never fails.
+            _ => Ok(Node::Concat(pieces)),
+        }
+    }
+
+    fn array(&mut self) -> Result<Node, Box<dyn Error>> {
+        self.next();
+        let mut elements = Vec::new();
+        loop {
+            self.skip_space();
+            if self.eat("]") {
+                return Ok(Node::Array(elements));
+            }
+            elements.push(self.value(&[])?);
+            // Elements are separated by commas or newlines.
+            self.skip_blanks();
+            match self.peek() {
+                Some(',') => {
+                    self.next();
+                }
+                Some('\n' | ']') => {}
+                None => return Err(self.error("expected `]`")),
+                _ => return Err(self.error("expected `,` or newline")),
+            }
+        }
+    }
+
+    fn substitution(&mut self) -> Result<Node, Box<dyn Error>> {
+        self.pos += "${".len();
+        let optional = self.eat("?");
+        self.skip_blanks();
+        let path = self.key()?;
+        self.skip_blanks();
+        self.expect('}')?;
+        Ok(Node::Substitution {
+            path,
+            optional,
+            prefix: self.prefix.clone(),
+            env: true,
+        })
+    }
+
+    fn unquoted(&mut self) -> Result<Node, Box<dyn Error>> {
+        let start = self.pos;
+        while self.at_unquoted() {
+            self.next();
+        }
+        let text = &self.src[start..self.pos];
+        let value = match text {
+            "true" => Value::Boolean(true),
+            "false" => Value::Boolean(false),
+            "null" => Value::Nil,
+            _ => match number(text) {
+                Some(value) => value.map_err(|e| {
+                    self.pos = start;
+                    self.error(&e)
+                })?,
+                None => Value::String(text.to_string()),
+            },
+        };
+        Ok(Node::Scalar(value, text.to_string()))
+    }
+
+    fn string(&mut self) -> Result<String, Box<dyn Error>> {
+        if self.eat("\"\"\"") {
+            // Extra quotes before the closing ones belong to the string.
+            let start = self.pos;
+            let end = self.src[start..]
+                .find("\"\"\"")
+                .ok_or_else(|| self.error("unterminated string"))?;
+            let quotes = self.src[start + end..]
+                .chars()
+                .take_while(|&c| c == '"')
+                .count();
+            self.pos = start + end + quotes;
+            return Ok(self.src[start..self.pos - 3].to_string());
+        }
+        self.next();
+        let mut s = String::new();
+        loop {
+            match self.next() {
+                Some('"') => return Ok(s),
+                Some('\\') => match self.next() {
+                    Some('b') => s.push('\u{8}'),
+                    Some('f') => s.push('\u{c}'),
+                    Some('n') => s.push('\n'),
+                    Some('r') => s.push('\r'),
+                    Some('t') => s.push('\t'),
+                    Some('u') => {
+                        let mut code = self.hex_digits()?;
+                        // Combine a UTF-16 surrogate pair.
+                        if (0xd800..0xdc00).contains(&code) && self.eat("\\u") {
+                            match self.hex_digits()? {
+                                low @ 0xdc00..0xe000 => {
+                                    code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00)
+                                }
+                                _ => return Err(self.error("invalid unicode escape")),
+                            }
+                        }
+                        s.push(
+                            char::from_u32(code)
+                                .ok_or_else(|| self.error("invalid unicode escape"))?,
+                        );
+                    }
+                    Some(c @ ('"' | '\\' | '/')) => s.push(c),
+                    _ => return Err(self.error("invalid escape")),
+                },
+                Some('\n') | None => return Err(self.error("unterminated string")),
+                Some(c) => s.push(c),
+            }
+        }
+    }
+
+    fn hex_digits(&mut self) -> Result<u32, Box<dyn Error>> {
+        let digits = self.src[self.pos..].get(..4).unwrap_or_default();
+        let code = u32::from_str_radix(digits, 16)
+            .ok()
+            .filter(|_| digits.chars().all(|c| c.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error("invalid unicode escape"))?;
+        self.pos += 4;
+        Ok(code)
+    }
+
+    fn include_keyword(&mut self) -> bool {
+        let start = self.pos;
+        if self.eat("include") && self.at_blank() {
+            self.skip_blanks();
+            let rest = &self.src[self.pos..];
+            if ["\"", "file(", "required(", "url(", "classpath("]
+                .iter()
+                .any(|s| rest.starts_with(s))
+            {
+                return true;
+            }
+        }
+        self.pos = start;
+        false
+    }
+
+    fn include(&mut self, path: &[String]) -> Result<Vec<(String, Node)>, Box<dyn Error>> {
+        let start = self.pos;
+        let required = self.eat("required(");
+        let name = self.resource()?;
+        if required {
+            self.skip_blanks();
+            self.expect(')')?;
+        }
+        let dir = self.dir.ok_or_else(|| {
+            self.pos = start;
+            self.error("cannot resolve include without the current file")
+        })?;
+        // Without an extension, all supported files of that name are included.
+        let file = dir.join(&name);
+        let files = if file.extension().is_some() {
+            vec![file]
+        } else {
+            vec![file.with_extension("conf"), file.with_extension("json")]
+        };
+        let mut fields = Vec::new();
+        let mut found = false;
+        for file in files {
+            let content = match std::fs::read_to_string(&file) {
+                Ok(content) => content,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => {
+                    self.pos = start;
+                    return Err(self.error(&format!("cannot include `{}`: {e}", file.display())));
+                }
+            };
+            found = true;
+            let canonical = file.canonicalize()?;
+            if self.includes.contains(&canonical) {
+                self.pos = start;
+                return Err(self.error(&format!("cyclic include of `{}`", file.display())));
+            }
+            let mut parser = Parser {
+                src: &content,
+                pos: 0,
+                dir: file.parent(),
+                prefix: path.to_vec(),
+                includes: [self.includes.as_slice(), &[canonical]].concat(),
+            };
+            fields.extend(
+                parser
+                    .document()
+                    .map_err(|e| format!("{e} in `{}`", file.display()))?,
+            );
+        }
+        if required && !found {
+            self.pos = start;
+            return Err(self.error(&format!("cannot find required include `{name}`")));
+        }
+        Ok(fields)
+    }
+
+    fn resource(&mut self) -> Result<String, Box<dyn Error>> {
+        self.skip_blanks();
+        if self.peek() == Some('"') {
+            return self.string();
+        }
+        if !self.eat("file(") {
+            return Err(self.error("only file includes are supported"));
+        }
+        self.skip_blanks();
+        if self.peek() != Some('"') {
+            return Err(self.error("expected string"));
+        }
+        let name = self.string()?;
+        self.skip_blanks();
+        self.expect(')')?;
+        Ok(name)
+    }
+}
+
+// Fails if an integer is beyond the range of 128-bit integers.
+fn number(s: &str) -> Option<Result<Value, String>> {
+    let unsigned = s.strip_prefix('-').unwrap_or(s);
+    let digits = |s: &str| s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let int = digits(unsigned);
+    if int == 0 || (int > 1 && unsigned.starts_with('0')) {
+        return None;
+    }
+    let mut rest = &unsigned[int..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let n = digits(fraction);
+        if n == 0 {
+            return None;
+        }
+        rest = &fraction[n..];
+    }
+    if let Some(exponent) = rest.strip_prefix(['e', 'E']) {
+        let exponent = exponent.strip_prefix(['-', '+']).unwrap_or(exponent);
+        let n = digits(exponent);
+        if n == 0 {
+            return None;
+        }
+        rest = &exponent[n..];
+    }
+    if !rest.is_empty() {
+        return None;
+    }
+    Some(if let Ok(value) = s.parse::<u128>() {
+        Ok(Value::PosInt(value))
+    } else if let Ok(value) = s.parse::<i128>() {
+        // `-0` is zero like `0`.
+        Ok(if value == 0 {
+            Value::PosInt(0)
+        } else {
+            Value::NegInt(value)
+        })
+    } else if !s.contains(['.', 'e', 'E']) {
+        Err(format!("Integer `{s}` is out of the 128-bit range"))
+    } else {
+        s.parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .map(Value::Float)
+            .ok_or_else(|| format!("Number `{s}` is out of range"))
+    })
+}
+
+// An assignment of a field, to be merged with other assignments of the same field.
+#[derive(Clone)]
+enum Layer<'a> {
+    Node(&'a Node),
+    Value(Value),
+}
+
+struct Resolver<'a> {
+    root: &'a Node,
+    // Nodes being resolved, which are skipped when referred to again.
+    stack: Vec<&'a Node>,
+}
+
+impl<'a> Resolver<'a> {
+    // Resolves a node, or returns `None` if it is an undefined optional substitution.
+    fn node(&mut self, node: &'a Node) -> Result<Option<Value>, Box<dyn Error>> {
+        if let Node::Object(_) = node {
+            return self.merge(vec![Layer::Node(node)]);
+        }
+        self.stack.push(node);
+        let value = match node {
+            Node::Scalar(value, _) => Some(value.clone()),
+            Node::Space(space) => Some(Value::String(space.clone())),
+            Node::Array(elements) => {
+                let mut values = Vec::new();
+                for element in elements {
+                    values.extend(self.node(element)?);
+                }
+                Some(Value::Array(values))
+            }
+            Node::Object(_) => unreachable!(),
+            Node::Substitution {
+                path,
+                optional,
+                prefix,
+                env,
+            } => {
+                let mut value = None;
+                if !prefix.is_empty() {
+                    value = self.lookup(&[prefix.as_slice(), path].concat())?;
+                }
+                if value.is_none() {
+                    value = self.lookup(path)?;
+                }
+                if value.is_none() && *env {
+                    value = std::env::var(path.join(".")).ok().map(Value::String);
+                }
+                if value.is_none() && !optional {
+                    Err(format!(
+                        "Substitution `${{{}}}` is undefined or refers to itself",
+                        path.join(".")
+                    ))?;
+                }
+                value
+            }
+            Node::Concat(pieces) => self.concat(pieces)?,
+        };
+        self.stack.pop();
+        Ok(value)
+    }
+
+    fn concat(&mut self, pieces: &'a [Node]) -> Result<Option<Value>, Box<dyn Error>> {
+        let mut string = String::new();
+        let mut scalars = false;
+        let mut arrays: Option<Vec<Value>> = None;
+        let mut tables = Vec::new();
+        for piece in pieces {
+            let value = match piece {
+                Node::Space(space) => {
+                    string.push_str(space);
+                    continue;
+                }
+                Node::Scalar(_, text) => {
+                    scalars = true;
+                    string.push_str(text);
+                    continue;
+                }
+                piece => self.node(piece)?,
+            };
+            match value {
+                None => {}
+                Some(Value::Array(mut elements)) => {
+                    arrays.get_or_insert_default().append(&mut elements)
+                }
+                Some(table @ Value::Table(_)) => tables.push(Layer::Value(table)),
+                Some(value) => {
+                    scalars = true;
+                    string.push_str(&match value {
+                        Value::Nil => "null".to_string(),
+                        Value::Boolean(value) => value.to_string(),
+                        Value::PosInt(value) => value.to_string(),
+                        Value::NegInt(value) => value.to_string(),
+                        Value::Float(value) => value.to_string(),
+                        Value::String(value) => value,
+                        Value::Bytes(_)
+                        | Value::Datetime(_)
+                        | Value::Array(_)
+                        | Value::Table(_) => {
+                            unreachable!()
+                        }
+                    });
+                }
+            }
+        }
+        match (scalars, arrays, tables.is_empty()) {
+            (false, None, true) => Ok(None),
+            (true, None, true) => Ok(Some(Value::String(string))),
+            (false, Some(elements), true) => Ok(Some(Value::Array(elements))),
+            (false, None, false) => self.merge(tables),
+            _ => Err("Cannot concatenate values of different types")?,
+        }
+    }
+
+    fn lookup(&mut self, path: &[String]) -> Result<Option<Value>, Box<dyn Error>> {
+        let mut layers = vec![Layer::Node(self.root)];
+        for key in path {
+            layers = self.field(layers, key)?;
+        }
+        self.truncate(&mut layers, true);
+        self.merge(layers)
+    }
+
+    // A field refers to its previous assignments when resolved within its own assignment.
+    // Objects being resolved can still be looked into, as their fields are resolved separately.
+    fn truncate(&self, layers: &mut Vec<Layer<'a>>, objects: bool) {
+        if let Some(index) = layers.iter().position(|layer| {
+            matches!(layer, Layer::Node(node)
+                if (objects || !matches!(node, Node::Object(_)))
+                    && self.stack.iter().any(|n| std::ptr::eq(*n, *node)))
+        }) {
+            layers.truncate(index);
+        }
+    }
+
+    // Collects the assignments of a field from the assignments of its parent.
+    fn field(
+        &mut self,
+        mut layers: Vec<Layer<'a>>,
+        key: &str,
+    ) -> Result<Vec<Layer<'a>>, Box<dyn Error>> {
+        self.truncate(&mut layers, false);
+        let mut fields = Vec::new();
+        for layer in layers.into_iter().rev() {
+            let value = match layer {
+                Layer::Node(Node::Object(entries)) => {
+                    fields.extend(
+                        entries
+                            .iter()
+                            .rev()
+                            .filter(|(k, _)| k == key)
+                            .map(|(_, v)| Layer::Node(v)),
+                    );
+                    continue;
+                }
+                Layer::Node(Node::Scalar(..) | Node::Array(_)) => break,
+                Layer::Node(node) => self.node(node)?,
+                Layer::Value(value) => Some(value),
+            };
+            match value {
+                // An undefined optional substitution keeps previous assignments.
+                None => {}
+                Some(Value::Table(table)) => fields.extend(
+                    table
+                        .into_iter()
+                        .find(|(k, _)| k == key)
+                        .map(|(_, v)| Layer::Value(v)),
+                ),
+                Some(_) => break,
+            }
+        }
+        fields.reverse();
+        Ok(fields)
+    }
+
+    // Merges assignments, where objects are merged with preceding objects
+    // and any other value overrides preceding assignments.
+    fn merge(&mut self, layers: Vec<Layer<'a>>) -> Result<Option<Value>, Box<dyn Error>> {
+        let mut objects = Vec::new();
+        for layer in layers.into_iter().rev() {
+            let value = match layer {
+                Layer::Node(node @ Node::Object(_)) => {
+                    objects.push(Layer::Node(node));
+                    continue;
+                }
+                Layer::Node(Node::Scalar(..) | Node::Array(_)) if !objects.is_empty() => break,
+                Layer::Node(node) => self.node(node)?,
+                Layer::Value(value) => Some(value),
+            };
+            match value {
+                None => {}
+                Some(table @ Value::Table(_)) => objects.push(Layer::Value(table)),
+                Some(value) if objects.is_empty() => return Ok(Some(value)),
+                Some(_) => break,
+            }
+        }
+        if objects.is_empty() {
+            return Ok(None);
+        }
+        objects.reverse();
+        let mut keys: Vec<String> = Vec::new();
+        for object in &objects {
+            let names: Vec<&String> = match object {
+                Layer::Node(Node::Object(entries)) => entries.iter().map(|(k, _)| k).collect(),
+                Layer::Value(Value::Table(table)) => table.keys().collect(),
+                _ => unreachable!(),
+            };
+            for name in names {
+                if !keys.contains(name) {
+                    keys.push(name.clone());
+                }
+            }
+        }
+        let depth = self.stack.len();
+        self.stack
+            .extend(objects.iter().filter_map(|object| match object {
+                Layer::Node(node) => Some(*node),
+                Layer::Value(_) => None,
+            }));
+        let mut table = Map::new();
+        for key in keys {
+            let fields = self.field(objects.clone(), &key)?;
+            if let Some(value) = self.merge(fields)? {
+                table.insert(key, value);
+            }
+        }
+        self.stack.truncate(depth);
+        Ok(Some(Value::Table(table)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(s: &str) -> Map<String, Value> {
+        match parse(s, None).unwrap() {
+            Value::Table(table) => table,
+            _ => panic!("not a table"),
+        }
+    }
+
+    fn get<'a>(value: &'a Value, key: &str) -> &'a Value {
+        match value {
+            Value::Table(table) => &table[key],
+            _ => panic!("not a table"),
+        }
+    }
+
+    fn string(s: &str) -> Value {
+        Value::String(s.to_string())
+    }
+
+    #[test]
+    fn objects_merge() {
+        let table = table(
+            r#"
+            a { x = 1, y = 2 }
+            a { y = 3, z = 4 }
+            b = { x = 1 }
+            b = 2
+            c = ${a} { x = 5 }
+            "#,
+        );
+        assert_eq!(get(&table["a"], "x"), &Value::PosInt(1));
+        assert_eq!(get(&table["a"], "y"), &Value::PosInt(3));
+        assert_eq!(get(&table["a"], "z"), &Value::PosInt(4));
+        assert_eq!(table["b"], Value::PosInt(2));
+        assert_eq!(get(&table["c"], "x"), &Value::PosInt(5));
+        assert_eq!(get(&table["c"], "z"), &Value::PosInt(4));
+    }
+
+    #[test]
+    fn self_references() {
+        let table = table(
+            r#"
+            a = [1]
+            a = ${a} [2]
+            a += 3
+            s = foo
+            s = ${s}bar
+            "#,
+        );
+        assert_eq!(
+            table["a"],
+            Value::Array(vec![Value::PosInt(1), Value::PosInt(2), Value::PosInt(3)])
+        );
+        assert_eq!(table["s"], string("foobar"));
+        assert!(parse("a = ${a}", None).is_err());
+        assert!(parse("a = ${b}\nb = ${a}", None).is_err());
+    }
+
+    #[test]
+    fn optional_substitutions() {
+        let table = table(
+            r#"
+            a = 1
+            a = ${?undefined.key}
+            b = ${?undefined.key}
+            c = [${?undefined.key}]
+            "#,
+        );
+        assert_eq!(table["a"], Value::PosInt(1));
+        assert!(!table.contains_key("b"));
+        assert_eq!(table["c"], Value::Array(Vec::new()));
+        assert!(parse("a = ${undefined.key}", None).is_err());
+    }
+
+    #[test]
+    fn environment_variables() {
+        // Cargo sets this when running tests.
+        let table = table(
+            r#"
+            name = ${CARGO_PKG_NAME}
+            optional = default
+            optional = ${?CARGO_PKG_NAME}
+            "#,
+        );
+        assert_eq!(table["name"], string("inline-config-macros"));
+        assert_eq!(table["optional"], string("inline-config-macros"));
+        // The config takes precedence.
+        let table = self::table(
+            "CARGO_PKG_NAME = config
+name = ${CARGO_PKG_NAME}",
+        );
+        assert_eq!(table["name"], string("config"));
+        // `+=` starts afresh.
+        let table = self::table("CARGO_PKG_NAME += 1");
+        assert_eq!(
+            table["CARGO_PKG_NAME"],
+            Value::Array(vec![Value::PosInt(1)])
+        );
+    }
+
+    #[test]
+    fn includes() {
+        let dir = std::env::temp_dir().join(format!("inline-config-hocon-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("base.conf"), "a { x = 1, y = 2 }\nb = ${a.x}").unwrap();
+        std::fs::write(dir.join("base.json"), r#"{ "c": 3 }"#).unwrap();
+        let value = parse(
+            r#"
+            include "base"
+            a { y = 3 }
+            nested { include file("base.conf") }
+            "#,
+            Some(&dir),
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+        let Value::Table(table) = value.unwrap() else {
+            panic!("not a table");
+        };
+        assert_eq!(get(&table["a"], "x"), &Value::PosInt(1));
+        assert_eq!(get(&table["a"], "y"), &Value::PosInt(3));
+        assert_eq!(table["b"], Value::PosInt(1));
+        assert_eq!(table["c"], Value::PosInt(3));
+        assert_eq!(get(&table["nested"], "b"), &Value::PosInt(1));
+        assert!(
+            parse(
+                r#"include required("missing")"#,
+                Some(&std::env::temp_dir())
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        for (text, value) in [
+            ("1", Value::PosInt(1)),
+            ("-1", Value::NegInt(-1)),
+            ("-0", Value::PosInt(0)),
+            ("1.5", Value::Float(1.5)),
+            ("1e3", Value::Float(1e3)),
+            ("1e+3", Value::Float(1e3)),
+            ("1E-3", Value::Float(1e-3)),
+        ] {
+            assert_eq!(number(text), Some(Ok(value)), "{text}");
+        }
+        for text in ["01", "1.", ".5", "1e", "1e+", "+1", "1x"] {
+            assert_eq!(number(text), None, "{text}");
+        }
+        assert_eq!(
+            number("340282366920938463463374607431768211455"),
+            Some(Ok(Value::PosInt(u128::MAX)))
+        );
+        for text in [
+            "340282366920938463463374607431768211456",
+            "-170141183460469231731687303715884105729",
+            "1e400",
+        ] {
+            assert!(matches!(number(text), Some(Err(_))), "{text}");
+        }
+    }
+}
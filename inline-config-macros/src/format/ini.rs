@@ -0,0 +1,168 @@
+use crate::value::{Map, Value};
+use darling::FromMeta;
+use std::error::Error;
+
+#[derive(Debug, Default, FromMeta, PartialEq)]
+#[darling(default, from_none = || Some(Self::default()))]
+pub struct Options {
+    untyped: bool,
+}
+
+pub fn parse(s: &str, options: &Options) -> Result<Value, Box<dyn Error>> {
+    let value = ini::Ini::load_from_str_opt(
+        s,
+        ini::ParseOption {
+            enabled_quote: false,
+            enabled_escape: false,
+            ..Default::default()
+        },
+    )?;
+    let mut root = Map::new();
+    for (section, properties) in &value {
+        // Dotted section names like `[server.tls]` nest.
+        let mut table = &mut root;
+        for name in section.into_iter().flat_map(|section| section.split('.')) {
+            table = match table
+                .entry(name.trim().to_string())
+                .or_insert_with(|| Value::Table(Map::new()))
+            {
+                Value::Table(table) => table,
+                _ => Err(format!(
+                    "Section `{}` conflicts with a key",
+                    section.unwrap()
+                ))?,
+            };
+        }
+        for (key, value) in properties {
+            if let Some(Value::Table(_)) = table.insert(key.to_string(), morph(value, options)) {
+                Err(format!("Key `{key}` conflicts with a section"))?;
+            }
+        }
+    }
+    Ok(Value::Table(root))
+}
+
+fn morph(value: &str, options: &Options) -> Value {
+    if let Some(value) = ['"', '\'']
+        .into_iter()
+        .find_map(|quote| value.strip_prefix(quote)?.strip_suffix(quote))
+    {
+        return Value::String(value.to_string());
+    }
+    if options.untyped {
+        return Value::String(value.to_string());
+    }
+    if value.eq_ignore_ascii_case("true") {
+        Value::Boolean(true)
+    } else if value.eq_ignore_ascii_case("false") {
+        Value::Boolean(false)
+    } else if !is_integer(value) {
+        Value::String(value.to_string())
+    } else if let Ok(value) = value.parse() {
+        Value::PosInt(value)
+    } else if let Ok(value) = value.parse() {
+        Value::NegInt(value)
+    } else {
+        Value::String(value.to_string())
+    }
+}
+
+// Only integers in canonical form are typed, so that values like `007`, `+5` or `-0` stay strings.
+fn is_integer(value: &str) -> bool {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    !digits.is_empty()
+        && digits.bytes().all(|b| b.is_ascii_digit())
+        && (value == "0" || !digits.starts_with('0'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(s: &str, options: &Options) -> Map<String, Value> {
+        match parse(s, options).unwrap() {
+            Value::Table(table) => table,
+            _ => panic!("not a table"),
+        }
+    }
+
+    #[test]
+    fn sections_nest() {
+        let table = table(
+            "
+            name = root
+            [server.tls]
+            port = 443
+            ",
+            &Options::default(),
+        );
+        assert_eq!(table["name"], Value::String("root".to_string()));
+        let Value::Table(server) = &table["server"] else {
+            panic!()
+        };
+        let Value::Table(tls) = &server["tls"] else {
+            panic!()
+        };
+        assert_eq!(tls["port"], Value::PosInt(443));
+    }
+
+    #[test]
+    fn canonical_values_are_typed() {
+        let table = table(
+            "
+            a = 5
+            b = -5
+            c = 0
+            d = TRUE
+            e = \"5\"
+            f = 340282366920938463463374607431768211456
+            ",
+            &Options::default(),
+        );
+        assert_eq!(table["a"], Value::PosInt(5));
+        assert_eq!(table["b"], Value::NegInt(-5));
+        assert_eq!(table["c"], Value::PosInt(0));
+        assert_eq!(table["d"], Value::Boolean(true));
+        assert_eq!(table["e"], Value::String("5".to_string()));
+        assert_eq!(
+            table["f"],
+            Value::String("340282366920938463463374607431768211456".to_string())
+        );
+    }
+
+    #[test]
+    fn ambiguous_values_are_strings() {
+        let table = table(
+            "
+            a = 007
+            b = +5
+            c = -0
+            d = 1.5
+            e = -
+            ",
+            &Options::default(),
+        );
+        for (key, value) in [
+            ("a", "007"),
+            ("b", "+5"),
+            ("c", "-0"),
+            ("d", "1.5"),
+            ("e", "-"),
+        ] {
+            assert_eq!(table[key], Value::String(value.to_string()));
+        }
+    }
+
+    #[test]
+    fn untyped() {
+        let table = table("a = 5\nb = true", &Options { untyped: true });
+        assert_eq!(table["a"], Value::String("5".to_string()));
+        assert_eq!(table["b"], Value::String("true".to_string()));
+    }
+
+    #[test]
+    fn conflicts() {
+        assert!(parse("a = 1\n[a]\nb = 2", &Options::default()).is_err());
+        assert!(parse("[a.b]\nc = 1\n[a]\nb = 2", &Options::default()).is_err());
+    }
+}
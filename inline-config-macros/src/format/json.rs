@@ -11,13 +11,7 @@ fn morph(value: json::Value) -> Result<Value, Box<dyn Error>> {
     Ok(match value {
         json::Value::Null => Value::Nil,
         json::Value::Bool(value) => Value::Boolean(value),
-        json::Value::Number(value) => value.as_u64().map(Value::PosInt).unwrap_or_else(|| {
-            value.as_i64().map(Value::NegInt).unwrap_or_else(|| {
-                Value::Float(
-                    value.as_f64().unwrap(), // Never fails.
-                )
-            })
-        }),
+        json::Value::Number(value) => number(value)?,
         json::Value::String(value) => Value::String(value),
         json::Value::Array(value) => {
             Value::Array(value.into_iter().map(morph).collect::<Result<_, _>>()?)
@@ -30,3 +24,56 @@ fn morph(value: json::Value) -> Result<Value, Box<dyn Error>> {
         ),
     })
 }
+
+// Integers beyond 64 bits are kept exact by the `arbitrary_precision` feature of `serde_json`,
+// and otherwise parsed as floats.
+fn number(value: json::Number) -> Result<Value, Box<dyn Error>> {
+    if let Some(value) = value.as_u128() {
+        Ok(Value::PosInt(value))
+    } else if let Some(value) = value.as_i128() {
+        Ok(if value.is_negative() {
+            Value::NegInt(value)
+        } else {
+            Value::PosInt(value as u128)
+        })
+    } else if !value.to_string().contains(['.', 'e', 'E']) {
+        Err(format!("Integer `{value}` is out of the 128-bit range"))?
+    } else {
+        Ok(Value::Float(value.as_f64().ok_or_else(|| {
+            format!("Number `{value}` is out of range")
+        })?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "json-arbitrary-precision")]
+    #[test]
+    fn integers_beyond_64_bits() {
+        assert_eq!(
+            parse("1180591620717411303424").unwrap(),
+            Value::PosInt(1 << 70)
+        );
+        assert_eq!(
+            parse("-1180591620717411303424").unwrap(),
+            Value::NegInt(-(1 << 70))
+        );
+        assert!(parse("340282366920938463463374607431768211456").is_err());
+        assert_eq!(parse("1.5e3").unwrap(), Value::Float(1.5e3));
+    }
+
+    #[cfg(not(feature = "json-arbitrary-precision"))]
+    #[test]
+    fn integers_beyond_64_bits_as_floats() {
+        assert_eq!(
+            parse("18446744073709551615").unwrap(),
+            Value::PosInt(u64::MAX.into())
+        );
+        assert_eq!(
+            parse("1180591620717411303424").unwrap(),
+            Value::Float(1180591620717411303424.0)
+        );
+    }
+}
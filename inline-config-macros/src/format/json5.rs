@@ -0,0 +1,387 @@
+use crate::value::{Map, Value};
+use std::error::Error;
+
+// A hand-written parser, as JSON5 is a small superset of JSON.
+// Also accepts JSON with comments (`.jsonc`).
+// https://spec.json5.org/
+pub fn parse(s: &str) -> Result<Value, Box<dyn Error>> {
+    let mut parser = Parser { src: s, pos: 0 };
+    parser.skip_trivia()?;
+    let value = parser.value()?;
+    parser.skip_trivia()?;
+    if parser.peek().is_some() {
+        return Err(parser.error("trailing characters"));
+    }
+    Ok(value)
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, msg: &str) -> Box<dyn Error> {
+        let consumed = &self.src[..self.pos];
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed.chars().rev().take_while(|&c| c != '\n').count() + 1;
+        format!("{msg} at line {line} column {column}").into()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        let matched = self.src[self.pos..].starts_with(s);
+        if matched {
+            self.pos += s.len();
+        }
+        matched
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), Box<dyn Error>> {
+        if self.next() == Some(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{c}`")))
+        }
+    }
+
+    fn skip_trivia(&mut self) -> Result<(), Box<dyn Error>> {
+        loop {
+            if self.eat("//") {
+                while !matches!(
+                    self.next(),
+                    None | Some('\n' | '\r' | '\u{2028}' | '\u{2029}')
+                ) {}
+            } else if self.eat("/*") {
+                while !self.eat("*/") {
+                    if self.next().is_none() {
+                        return Err(self.error("unterminated block comment"));
+                    }
+                }
+            } else if self
+                .peek()
+                .is_some_and(|c| c.is_whitespace() || c == '\u{feff}')
+            {
+                self.next();
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, Box<dyn Error>> {
+        match self.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some(quote @ ('"' | '\'')) => self.string(quote).map(Value::String),
+            Some('0'..='9' | '+' | '-' | '.' | 'I' | 'N') => self.number(),
+            Some(_) => {
+                if self.eat("null") {
+                    Ok(Value::Nil)
+                } else if self.eat("true") {
+                    Ok(Value::Boolean(true))
+                } else if self.eat("false") {
+                    Ok(Value::Boolean(false))
+                } else {
+                    Err(self.error("expected value"))
+                }
+            }
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, Box<dyn Error>> {
+        self.expect('{')?;
+        let mut table = Map::new();
+        loop {
+            self.skip_trivia()?;
+            if self.eat("}") {
+                return Ok(Value::Table(table));
+            }
+            let key = match self.peek() {
+                Some(quote @ ('"' | '\'')) => self.string(quote)?,
+                _ => self.identifier()?,
+            };
+            self.skip_trivia()?;
+            self.expect(':')?;
+            self.skip_trivia()?;
+            table.insert(key, self.value()?);
+            self.skip_trivia()?;
+            if !self.eat(",") {
+                self.skip_trivia()?;
+                self.expect('}')?;
+                return Ok(Value::Table(table));
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, Box<dyn Error>> {
+        self.expect('[')?;
+        let mut array = Vec::new();
+        loop {
+            self.skip_trivia()?;
+            if self.eat("]") {
+                return Ok(Value::Array(array));
+            }
+            array.push(self.value()?);
+            self.skip_trivia()?;
+            if !self.eat(",") {
+                self.skip_trivia()?;
+                self.expect(']')?;
+                return Ok(Value::Array(array));
+            }
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String, Box<dyn Error>> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_alphanumeric() || matches!(c, '$' | '_'))
+        {
+            self.next();
+        }
+        let ident = &self.src[start..self.pos];
+        if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+            self.pos = start;
+            return Err(self.error("expected key"));
+        }
+        Ok(ident.to_string())
+    }
+
+    fn string(&mut self, quote: char) -> Result<String, Box<dyn Error>> {
+        self.expect(quote)?;
+        let mut string = String::new();
+        loop {
+            match self.next() {
+                Some(c) if c == quote => return Ok(string),
+                Some('\\') => match self.next() {
+                    Some('b') => string.push('\u{8}'),
+                    Some('f') => string.push('\u{c}'),
+                    Some('n') => string.push('\n'),
+                    Some('r') => string.push('\r'),
+                    Some('t') => string.push('\t'),
+                    Some('v') => string.push('\u{b}'),
+                    Some('0') if !self.peek().is_some_and(|c| c.is_ascii_digit()) => {
+                        string.push('\0')
+                    }
+                    Some('0'..='9') => return Err(self.error("invalid escape")),
+                    Some('x') => {
+                        let code = self.hex_digits(2)?;
+                        string.push(char::from_u32(code).unwrap()); // Never fails.
+                    }
+                    Some('u') => {
+                        let code = self.hex_digits(4)?;
+                        // Combine a UTF-16 surrogate pair.
+                        let code = if (0xd800..0xdc00).contains(&code) && self.eat("\\u") {
+                            match self.hex_digits(4)? {
+                                low @ 0xdc00..0xe000 => {
+                                    0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00)
+                                }
+                                _ => return Err(self.error("invalid unicode escape")),
+                            }
+                        } else {
+                            code
+                        };
+                        string.push(
+                            char::from_u32(code)
+                                .ok_or_else(|| self.error("invalid unicode escape"))?,
+                        );
+                    }
+                    // Line continuation.
+                    Some('\r') => {
+                        self.eat("\n");
+                    }
+                    Some('\n' | '\u{2028}' | '\u{2029}') => {}
+                    Some(c) => string.push(c),
+                    None => return Err(self.error("unterminated string")),
+                },
+                Some('\n' | '\r') | None => return Err(self.error("unterminated string")),
+                Some(c) => string.push(c),
+            }
+        }
+    }
+
+    fn hex_digits(&mut self, len: usize) -> Result<u32, Box<dyn Error>> {
+        let digits = self
+            .src
+            .get(self.pos..self.pos + len)
+            .filter(|digits| digits.chars().all(|c| c.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error("invalid hex escape"))?;
+        self.pos += len;
+        Ok(u32::from_str_radix(digits, 16).unwrap()) // Never fails.
+    }
+
+    fn number(&mut self) -> Result<Value, Box<dyn Error>> {
+        let negative = self.eat("-");
+        if !negative {
+            self.eat("+");
+        }
+        let sign = if negative { -1.0 } else { 1.0 };
+        if self.eat("Infinity") {
+            return Ok(Value::Float(sign * f64::INFINITY));
+        }
+        if self.eat("NaN") {
+            return Ok(Value::Float(f64::NAN));
+        }
+        if self.eat("0x") || self.eat("0X") {
+            let start = self.pos;
+            while self.peek().is_some_and(|c| c.is_ascii_hexdigit()) {
+                self.next();
+            }
+            let magnitude = u128::from_str_radix(&self.src[start..self.pos], 16)
+                .map_err(|e| self.error(&e.to_string()))?;
+            return int(negative, magnitude).ok_or_else(|| self.error("integer out of range"));
+        }
+        let start = self.pos;
+        let mut is_float = false;
+        while let Some(c) = self.peek() {
+            match c {
+                '0'..='9' => {}
+                '.' | 'e' | 'E' => is_float = true,
+                '+' | '-' if self.src[..self.pos].ends_with(['e', 'E']) => {}
+                _ => break,
+            }
+            self.next();
+        }
+        let digits = &self.src[start..self.pos];
+        if !digits.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+            return Err(self.error("invalid number"));
+        }
+        if digits.len() > 1
+            && digits.starts_with('0')
+            && digits[1..].starts_with(|c: char| c.is_ascii_digit())
+        {
+            return Err(self.error("leading zeros are not allowed"));
+        }
+        if !is_float {
+            return digits
+                .parse()
+                .ok()
+                .and_then(|magnitude| int(negative, magnitude))
+                .ok_or_else(|| self.error("integer out of range"));
+        }
+        // Leading and trailing decimal points are accepted by the float parser.
+        let magnitude: f64 = digits.parse().map_err(|_| self.error("invalid number"))?;
+        Ok(Value::Float(sign * magnitude))
+    }
+}
+
+// Fails beyond the range of 128-bit integers.
+fn int(negative: bool, magnitude: u128) -> Option<Value> {
+    if !negative || magnitude == 0 {
+        Some(Value::PosInt(magnitude))
+    } else {
+        0i128.checked_sub_unsigned(magnitude).map(Value::NegInt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(s: &str) -> Map<String, Value> {
+        match parse(s).unwrap() {
+            Value::Table(table) => table,
+            _ => panic!("not a table"),
+        }
+    }
+
+    #[test]
+    fn comments_and_trailing_commas() {
+        let table = table(
+            "
+            // A line comment.
+            {
+                /* A block comment. */
+                a: [1, 2,],
+                b: { c: true, },
+            }
+            ",
+        );
+        assert_eq!(
+            table["a"],
+            Value::Array(vec![Value::PosInt(1), Value::PosInt(2)])
+        );
+        let Value::Table(b) = &table["b"] else {
+            panic!()
+        };
+        assert_eq!(b["c"], Value::Boolean(true));
+    }
+
+    #[test]
+    fn keys() {
+        let table = table(r#"{ $id_1: 1, 'single': 2, "double": 3 }"#);
+        assert_eq!(table["$id_1"], Value::PosInt(1));
+        assert_eq!(table["single"], Value::PosInt(2));
+        assert_eq!(table["double"], Value::PosInt(3));
+        assert!(parse("{ 1a: 1 }").is_err());
+    }
+
+    #[test]
+    fn strings() {
+        let table = table(
+            r#"{
+                a: 'It\'s "quoted"',
+                b: "line \
+continued",
+                c: "\x41\u00e9\ud83d\ude00\0",
+                d: "\q",
+            }"#,
+        );
+        assert_eq!(table["a"], Value::String(r#"It's "quoted""#.to_string()));
+        assert_eq!(table["b"], Value::String("line continued".to_string()));
+        assert_eq!(table["c"], Value::String("A\u{e9}\u{1f600}\0".to_string()));
+        assert_eq!(table["d"], Value::String("q".to_string()));
+    }
+
+    #[test]
+    fn invalid_escapes() {
+        assert!(parse(r#""\01""#).is_err());
+        assert!(parse(r#""\1""#).is_err());
+        assert!(parse(r#""\x4""#).is_err());
+        assert!(parse("\"unterminated\nstring\"").is_err());
+    }
+
+    #[test]
+    fn numbers() {
+        let table = table(
+            "{ a: 0x1F, b: -0xff, c: +1, d: .5, e: 5., f: 1e3, g: -Infinity, h: NaN, i: 0, j: -0.5 }",
+        );
+        assert_eq!(table["a"], Value::PosInt(31));
+        assert_eq!(table["b"], Value::NegInt(-255));
+        assert_eq!(table["c"], Value::PosInt(1));
+        assert_eq!(table["d"], Value::Float(0.5));
+        assert_eq!(table["e"], Value::Float(5.0));
+        assert_eq!(table["f"], Value::Float(1000.0));
+        assert_eq!(table["g"], Value::Float(f64::NEG_INFINITY));
+        assert!(matches!(table["h"], Value::Float(value) if value.is_nan()));
+        assert_eq!(table["i"], Value::PosInt(0));
+        assert_eq!(table["j"], Value::Float(-0.5));
+    }
+
+    #[test]
+    fn invalid_numbers() {
+        assert!(parse("01").is_err());
+        assert!(parse("-007").is_err());
+        assert!(parse("00.5").is_err());
+        assert!(parse("0x").is_err());
+        assert!(parse(".").is_err());
+        assert!(parse("340282366920938463463374607431768211456").is_err());
+    }
+
+    #[test]
+    fn trailing_characters() {
+        assert!(parse("{} {}").is_err());
+        assert!(parse("[1,,]").is_err());
+    }
+}
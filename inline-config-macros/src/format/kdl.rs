@@ -0,0 +1,713 @@
+use crate::value::{Map, Value};
+use darling::FromMeta;
+use std::collections::HashSet;
+use std::error::Error;
+
+#[derive(Debug, Default, FromMeta, PartialEq)]
+#[darling(default, from_none = || Some(Self::default()))]
+pub struct Options {
+    #[darling(multiple)]
+    array: Vec<String>,
+}
+
+// A hand-written parser accepting both KDL v1 and v2 syntax.
+// https://kdl.dev/spec/
+pub fn parse(s: &str, options: &Options) -> Result<Value, Box<dyn Error>> {
+    let mut parser = Parser { src: s, pos: 0 };
+    parser.eat("\u{feff}");
+    let value = parser.nodes(options)?;
+    if parser.peek().is_some() {
+        return Err(parser.error("unexpected `}`"));
+    }
+    Ok(value)
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+struct Node {
+    name: String,
+    arguments: Vec<Value>,
+    // Properties and children, or nothing at all.
+    table: Option<Map<String, Value>>,
+}
+
+enum Entry {
+    Argument(Value),
+    Property(String, Value),
+}
+
+// Shapes the nodes sharing a name. Repeated nodes form an array of their values, like XML elements,
+// and nodes listed in `array` always do, taking arguments or tables of all of them.
+fn group(name: &str, nodes: Vec<Node>, options: &Options) -> Result<Value, Box<dyn Error>> {
+    if options.array.iter().any(|array| array == name) {
+        let mut values = Vec::new();
+        for node in nodes {
+            match node.table {
+                Some(_) if !node.arguments.is_empty() => Err(format!(
+                    "Node `{name}` of an array mixes arguments with properties or children"
+                ))?,
+                Some(table) => values.push(Value::Table(table)),
+                None => values.extend(node.arguments),
+            }
+        }
+        return Ok(Value::Array(values));
+    }
+    // Arguments of a node with properties or children are labels nesting the table,
+    // like `user "alice" role="admin"`, so such nodes are keyed by labels instead.
+    let is_labeled = |node: &Node| node.table.is_some() && !node.arguments.is_empty();
+    if nodes.iter().any(is_labeled) {
+        if !nodes.iter().all(is_labeled) {
+            Err(format!(
+                "Labeled node `{name}` conflicts with another node without labels"
+            ))?;
+        }
+        let mut table = Map::new();
+        let mut label_tables = HashSet::new();
+        for node in nodes {
+            insert_labeled(&mut table, &mut label_tables, name, node)?;
+        }
+        return Ok(Value::Table(table));
+    }
+    let mut values: Vec<Value> = nodes
+        .into_iter()
+        .map(|node| match node.table {
+            Some(table) => Value::Table(table),
+            None => match <[Value; 1]>::try_from(node.arguments) {
+                Ok([argument]) => argument,
+                Err(arguments) => Value::Array(arguments),
+            },
+        })
+        .collect();
+    Ok(match values.len() {
+        1 => values.pop().unwrap(), // Never fails.
+        _ => Value::Array(values),
+    })
+}
+
+fn insert_labeled(
+    table: &mut Map<String, Value>,
+    label_tables: &mut HashSet<Vec<String>>,
+    name: &str,
+    node: Node,
+) -> Result<(), Box<dyn Error>> {
+    let mut labels = Vec::new();
+    for argument in node.arguments {
+        match argument {
+            Value::String(label) => labels.push(label),
+            _ => Err(format!(
+                "Arguments of node `{name}` must be string labels along with properties or children"
+            ))?,
+        }
+    }
+    let path = [&[name.to_string()], labels.as_slice()].concat().join(".");
+    let conflict = || format!("Node `{path}` conflicts with another node");
+    let last = labels.pop().unwrap(); // Never fails.
+    let mut table = table;
+    for (depth, label) in labels.iter().enumerate() {
+        let prefix = labels[..=depth].to_vec();
+        if !table.contains_key(label) {
+            table.insert(label.clone(), Value::Table(Map::new()));
+            label_tables.insert(prefix.clone());
+        }
+        table = match table.get_mut(label) {
+            Some(Value::Table(table)) if label_tables.contains(&prefix) => table,
+            _ => Err(conflict())?,
+        };
+    }
+    if table.contains_key(&last) {
+        Err(conflict())?;
+    }
+    table.insert(last, Value::Table(node.table.unwrap_or_default()));
+    Ok(())
+}
+
+fn is_newline(c: char) -> bool {
+    matches!(
+        c,
+        '\n' | '\r' | '\u{85}' | '\u{b}' | '\u{c}' | '\u{2028}' | '\u{2029}'
+    )
+}
+
+fn is_identifier_char(c: char) -> bool {
+    !c.is_whitespace() && !"\\/(){};[]=\"#".contains(c)
+}
+
+impl Parser<'_> {
+    fn error(&self, msg: &str) -> Box<dyn Error> {
+        let consumed = &self.src[..self.pos];
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed.chars().rev().take_while(|&c| c != '\n').count() + 1;
+        format!("{msg} at line {line} column {column}").into()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        let matched = self.src[self.pos..].starts_with(s);
+        if matched {
+            self.pos += s.len();
+        }
+        matched
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), Box<dyn Error>> {
+        if self.next() == Some(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{c}`")))
+        }
+    }
+
+    fn block_comment(&mut self) -> Result<(), Box<dyn Error>> {
+        // Block comments nest.
+        let mut depth = 1;
+        while depth > 0 {
+            if self.eat("/*") {
+                depth += 1;
+            } else if self.eat("*/") {
+                depth -= 1;
+            } else if self.next().is_none() {
+                return Err(self.error("unterminated block comment"));
+            }
+        }
+        Ok(())
+    }
+
+    fn line_comment(&mut self) {
+        while self.peek().is_some_and(|c| !is_newline(c)) {
+            self.next();
+        }
+    }
+
+    // Whitespace within a node, including line continuations.
+    fn skip_node_space(&mut self) -> Result<(), Box<dyn Error>> {
+        loop {
+            if self.eat("/*") {
+                self.block_comment()?;
+            } else if self.eat("\\") {
+                self.skip_node_space()?;
+                if self.eat("//") {
+                    self.line_comment();
+                }
+                if !(self.eat("\r\n") || self.next().is_some_and(is_newline)) {
+                    return Err(self.error("expected newline after line continuation"));
+                }
+            } else if self
+                .peek()
+                .is_some_and(|c| c.is_whitespace() && !is_newline(c) || c == '\u{feff}')
+            {
+                self.next();
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    // Whitespace between nodes, including newlines and comments.
+    fn skip_line_space(&mut self) -> Result<(), Box<dyn Error>> {
+        loop {
+            self.skip_node_space()?;
+            if self.eat("//") {
+                self.line_comment();
+            } else if self.peek().is_some_and(is_newline) {
+                self.next();
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn nodes(&mut self, options: &Options) -> Result<Value, Box<dyn Error>> {
+        let mut groups: Map<String, Vec<Node>> = Map::new();
+        loop {
+            self.skip_line_space()?;
+            match self.peek() {
+                None | Some('}') => break,
+                _ => {}
+            }
+            if self.eat("/-") {
+                self.skip_line_space()?;
+                self.node(options)?;
+                continue;
+            }
+            let node = self.node(options)?;
+            groups.entry(node.name.clone()).or_default().push(node);
+        }
+        let mut table = Map::new();
+        for (name, nodes) in groups {
+            let value = group(&name, nodes, options)?;
+            table.insert(name, value);
+        }
+        Ok(Value::Table(table))
+    }
+
+    fn node(&mut self, options: &Options) -> Result<Node, Box<dyn Error>> {
+        self.type_annotation()?;
+        let name = self.string()?;
+        let mut arguments = Vec::new();
+        let mut properties = Map::new();
+        let mut children = None;
+        loop {
+            let before = self.pos;
+            self.skip_node_space()?;
+            let spaced = self.pos > before;
+            match self.peek() {
+                None | Some('}') => break,
+                Some(';') => {
+                    self.next();
+                    break;
+                }
+                Some(c) if is_newline(c) => break,
+                _ if self.eat("//") => {
+                    self.line_comment();
+                    break;
+                }
+                // Only slashdashed children may follow children.
+                _ if self.eat("/-") => {
+                    self.skip_node_space()?;
+                    if self.peek() == Some('{') {
+                        self.children(options)?;
+                    } else if children.is_some() {
+                        return Err(self.error("expected end of node after children"));
+                    } else {
+                        self.entry()?;
+                    }
+                }
+                _ if children.is_some() => {
+                    return Err(self.error("expected end of node after children"));
+                }
+                Some('{') => children = Some(self.children(options)?),
+                _ if !spaced => return Err(self.error("expected whitespace")),
+                _ => match self.entry()? {
+                    Entry::Argument(value) => arguments.push(value),
+                    // Later properties override former ones.
+                    Entry::Property(key, value) => {
+                        properties.insert(key, value);
+                    }
+                },
+            }
+        }
+        let table = match children {
+            Some(Value::Table(children)) => {
+                for (key, value) in children {
+                    if properties.insert(key.clone(), value).is_some() {
+                        Err(format!(
+                            "Child `{key}` of node `{name}` conflicts with a property"
+                        ))?;
+                    }
+                }
+                Some(properties)
+            }
+            // A bare node is an empty table.
+            _ => (!properties.is_empty() || arguments.is_empty()).then_some(properties),
+        };
+        Ok(Node {
+            name,
+            arguments,
+            table,
+        })
+    }
+
+    fn children(&mut self, options: &Options) -> Result<Value, Box<dyn Error>> {
+        self.expect('{')?;
+        let children = self.nodes(options)?;
+        self.expect('}')?;
+        Ok(children)
+    }
+
+    fn entry(&mut self) -> Result<Entry, Box<dyn Error>> {
+        if self.type_annotation()? {
+            return self.value().map(Entry::Argument);
+        }
+        let start = self.pos;
+        let value = self.value()?;
+        let end = self.pos;
+        self.skip_node_space()?;
+        if !self.eat("=") {
+            self.pos = end;
+            return Ok(Entry::Argument(value));
+        }
+        let key = match value {
+            Value::String(key) => key,
+            _ => {
+                self.pos = start;
+                return Err(self.error("expected property name"));
+            }
+        };
+        self.skip_node_space()?;
+        self.type_annotation()?;
+        self.value().map(|value| Entry::Property(key, value))
+    }
+
+    // Type annotations are ignored.
+    fn type_annotation(&mut self) -> Result<bool, Box<dyn Error>> {
+        if !self.eat("(") {
+            return Ok(false);
+        }
+        self.skip_node_space()?;
+        self.string()?;
+        self.skip_node_space()?;
+        self.expect(')')?;
+        self.skip_node_space()?;
+        Ok(true)
+    }
+
+    fn value(&mut self) -> Result<Value, Box<dyn Error>> {
+        let rest = &self.src[self.pos..];
+        if rest.starts_with(|c: char| c.is_ascii_digit())
+            || rest.starts_with(['+', '-', '.'])
+                && rest[1..].starts_with(|c: char| c.is_ascii_digit())
+        {
+            return self.number();
+        }
+        for (keyword, value) in [
+            ("#true", Value::Boolean(true)),
+            ("#false", Value::Boolean(false)),
+            ("#null", Value::Nil),
+            ("#inf", Value::Float(f64::INFINITY)),
+            ("#-inf", Value::Float(f64::NEG_INFINITY)),
+            ("#nan", Value::Float(f64::NAN)),
+        ] {
+            if self.eat(keyword) {
+                return Ok(value);
+            }
+        }
+        let rest = &self.src[self.pos..];
+        let quoted = !rest.starts_with(is_identifier_char)
+            || rest.starts_with("r\"")
+            || rest.starts_with("r#");
+        let string = self.string()?;
+        // KDL v1 keywords.
+        Ok(match string.as_str() {
+            "true" if !quoted => Value::Boolean(true),
+            "false" if !quoted => Value::Boolean(false),
+            "null" if !quoted => Value::Nil,
+            _ => Value::String(string),
+        })
+    }
+
+    // An identifier or a string.
+    fn string(&mut self) -> Result<String, Box<dyn Error>> {
+        if self.eat("\"\"\"") {
+            return self.multiline_string();
+        }
+        if self.peek() == Some('"') {
+            return self.quoted_string();
+        }
+        let rest = &self.src[self.pos..];
+        if rest.starts_with("r\"") || rest.starts_with("r#") || rest.starts_with('#') {
+            return self.raw_string();
+        }
+        let start = self.pos;
+        while self.peek().is_some_and(is_identifier_char) {
+            self.next();
+        }
+        let identifier = &self.src[start..self.pos];
+        if identifier.is_empty() {
+            return Err(self.error("expected identifier or string"));
+        }
+        Ok(identifier.to_string())
+    }
+
+    fn quoted_string(&mut self) -> Result<String, Box<dyn Error>> {
+        self.expect('"')?;
+        let mut string = String::new();
+        loop {
+            match self.next() {
+                Some('"') => return Ok(string),
+                Some('\\') => self.escape(&mut string)?,
+                Some(c) => string.push(c),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn escape(&mut self, string: &mut String) -> Result<(), Box<dyn Error>> {
+        match self.next() {
+            Some('n') => string.push('\n'),
+            Some('r') => string.push('\r'),
+            Some('t') => string.push('\t'),
+            Some('b') => string.push('\u{8}'),
+            Some('f') => string.push('\u{c}'),
+            Some('s') => string.push(' '),
+            Some(c @ ('\\' | '"' | '/')) => string.push(c),
+            Some('u') => {
+                self.expect('{')?;
+                let start = self.pos;
+                while self.peek().is_some_and(|c| c.is_ascii_hexdigit()) {
+                    self.next();
+                }
+                let c = u32::from_str_radix(&self.src[start..self.pos], 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| self.error("invalid unicode escape"))?;
+                self.expect('}')?;
+                string.push(c);
+            }
+            // Escaped whitespace is discarded.
+            Some(c) if c.is_whitespace() => {
+                while self.peek().is_some_and(char::is_whitespace) {
+                    self.next();
+                }
+            }
+            _ => return Err(self.error("invalid escape")),
+        }
+        Ok(())
+    }
+
+    fn raw_string(&mut self) -> Result<String, Box<dyn Error>> {
+        self.eat("r");
+        let start = self.pos;
+        while self.eat("#") {}
+        let hashes = &self.src[start..self.pos];
+        let multiline = self.eat("\"\"\"");
+        if !multiline {
+            self.expect('"')?;
+        }
+        let quotes = if multiline { "\"\"\"" } else { "\"" };
+        let terminator = format!("{quotes}{hashes}");
+        let start = self.pos;
+        let end = self.src[start..]
+            .find(&terminator)
+            .map(|offset| start + offset)
+            .ok_or_else(|| self.error("unterminated raw string"))?;
+        self.pos = end + terminator.len();
+        let content = &self.src[start..end];
+        if multiline {
+            Self::dedent(content).map_err(|e| self.error(e))
+        } else {
+            Ok(content.to_string())
+        }
+    }
+
+    fn multiline_string(&mut self) -> Result<String, Box<dyn Error>> {
+        let start = self.pos;
+        while !self.src[self.pos..].starts_with("\"\"\"") {
+            match self.next() {
+                Some('\\') => {
+                    self.next();
+                }
+                Some(_) => {}
+                None => return Err(self.error("unterminated multi-line string")),
+            }
+        }
+        let content = &self.src[start..self.pos];
+        self.pos += "\"\"\"".len();
+        // Escapes are processed after dedenting.
+        let content = Self::dedent(content).map_err(|e| self.error(e))?;
+        let mut parser = Parser {
+            src: &content,
+            pos: 0,
+        };
+        let mut string = String::new();
+        while let Some(c) = parser.next() {
+            match c {
+                '\\' => parser
+                    .escape(&mut string)
+                    .map_err(|_| self.error("invalid escape in multi-line string"))?,
+                c => string.push(c),
+            }
+        }
+        Ok(string)
+    }
+
+    // Removes the first and last lines, and the indentation of the last line from all others.
+    fn dedent(content: &str) -> Result<String, &'static str> {
+        let content = content
+            .strip_prefix("\r\n")
+            .or_else(|| content.strip_prefix(is_newline))
+            .ok_or("multi-line strings must start with a newline")?;
+        let (body, indent) = content.rsplit_once(is_newline).unwrap_or(("", content));
+        if !indent.chars().all(char::is_whitespace) {
+            return Err("multi-line strings must end on their own line");
+        }
+        body.lines()
+            .map(|line| {
+                if line.chars().all(char::is_whitespace) {
+                    Ok("")
+                } else {
+                    line.strip_prefix(indent)
+                        .ok_or("inconsistent indentation in multi-line string")
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|lines| lines.join("\n"))
+    }
+
+    fn number(&mut self) -> Result<Value, Box<dyn Error>> {
+        let negative = self.eat("-");
+        if !negative {
+            self.eat("+");
+        }
+        let radix = if self.eat("0x") {
+            16
+        } else if self.eat("0o") {
+            8
+        } else if self.eat("0b") {
+            2
+        } else {
+            10
+        };
+        let start = self.pos;
+        while self.peek().is_some_and(is_identifier_char) {
+            self.next();
+        }
+        let digits = self.src[start..self.pos].replace('_', "");
+        let is_float = radix == 10 && digits.contains(['.', 'e', 'E']);
+        if !is_float && !digits.is_empty() && digits.chars().all(|c| c.is_digit(radix)) {
+            // Fails beyond the range of 128-bit integers.
+            return u128::from_str_radix(&digits, radix)
+                .ok()
+                .and_then(|magnitude| {
+                    if !negative || magnitude == 0 {
+                        Some(Value::PosInt(magnitude))
+                    } else {
+                        0i128.checked_sub_unsigned(magnitude).map(Value::NegInt)
+                    }
+                })
+                .ok_or_else(|| self.error("integer out of range"));
+        }
+        let magnitude: f64 = digits
+            .parse()
+            .ok()
+            .filter(|_| radix == 10)
+            .ok_or_else(|| self.error("invalid number"))?;
+        Ok(Value::Float(if negative { -magnitude } else { magnitude }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(s: &str, options: &Options) -> Map<String, Value> {
+        match parse(s, options).unwrap() {
+            Value::Table(table) => table,
+            _ => panic!("not a table"),
+        }
+    }
+
+    fn get<'a>(value: &'a Value, key: &str) -> &'a Value {
+        match value {
+            Value::Table(table) => &table[key],
+            _ => panic!("not a table"),
+        }
+    }
+
+    #[test]
+    fn arguments() {
+        let table = table("one 1\nmany 1 2\nbare", &Options::default());
+        assert_eq!(table["one"], Value::PosInt(1));
+        assert_eq!(
+            table["many"],
+            Value::Array(vec![Value::PosInt(1), Value::PosInt(2)])
+        );
+        assert_eq!(table["bare"], Value::Table(Map::new()));
+    }
+
+    #[test]
+    fn properties_and_children() {
+        let table = table("server port=80 { tls enabled=#true }", &Options::default());
+        assert_eq!(get(&table["server"], "port"), &Value::PosInt(80));
+        assert_eq!(
+            get(get(&table["server"], "tls"), "enabled"),
+            &Value::Boolean(true)
+        );
+        let error = parse("server port=80 { port 81 }", &Options::default()).unwrap_err();
+        assert!(error.to_string().contains("conflicts with a property"));
+    }
+
+    #[test]
+    fn arguments_are_labels_along_with_properties() {
+        let table = table(
+            r#"
+            user "alice" role="admin"
+            user "bob" role="guest"
+            "#,
+            &Options::default(),
+        );
+        assert_eq!(
+            get(get(&table["user"], "alice"), "role"),
+            &Value::String("admin".to_string())
+        );
+        assert_eq!(
+            get(get(&table["user"], "bob"), "role"),
+            &Value::String("guest".to_string())
+        );
+        // The shape does not depend on how many users there are.
+        let table = self::table(r#"user "alice" role="admin""#, &Options::default());
+        assert_eq!(
+            get(get(&table["user"], "alice"), "role"),
+            &Value::String("admin".to_string())
+        );
+        for s in [
+            "user \"a\" x=1\nuser \"a\" y=2",
+            "user 1\nuser \"a\" x=1",
+            "user 1 x=1",
+        ] {
+            assert!(parse(s, &Options::default()).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn repeated_nodes() {
+        let table = table(
+            "port 80\nport 81 82\nserver x=1\nserver x=2",
+            &Options::default(),
+        );
+        assert_eq!(
+            table["port"],
+            Value::Array(vec![
+                Value::PosInt(80),
+                Value::Array(vec![Value::PosInt(81), Value::PosInt(82)]),
+            ])
+        );
+        let Value::Array(servers) = &table["server"] else {
+            panic!("not an array");
+        };
+        assert_eq!(get(&servers[1], "x"), &Value::PosInt(2));
+        assert!(parse("user \"a\" x=1\nuser 1", &Options::default()).is_err());
+        let options = Options {
+            array: vec!["port".to_string(), "rule".to_string()],
+        };
+        let table = self::table("port 80\nport 81 82\nrule x=1\nrule x=2", &options);
+        assert_eq!(
+            table["port"],
+            Value::Array(vec![
+                Value::PosInt(80),
+                Value::PosInt(81),
+                Value::PosInt(82),
+            ])
+        );
+        let Value::Array(rules) = &table["rule"] else {
+            panic!("not an array");
+        };
+        assert_eq!(get(&rules[1], "x"), &Value::PosInt(2));
+        let table = self::table("port 80", &options);
+        assert_eq!(table["port"], Value::Array(vec![Value::PosInt(80)]));
+        assert!(parse("rule 1 x=1", &options).is_err());
+    }
+
+    #[test]
+    fn slashdash() {
+        let table = table(
+            "/- skipped 1\nnode /-2 /-key=3 { child 4 } /-{ child 5 }",
+            &Options::default(),
+        );
+        assert_eq!(table.len(), 1);
+        assert_eq!(get(&table["node"], "child"), &Value::PosInt(4));
+        assert!(parse("node { child 1 } /-2", &Options::default()).is_err());
+        assert!(parse("node { child 1 } 2", &Options::default()).is_err());
+    }
+}
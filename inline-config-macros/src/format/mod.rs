@@ -1,52 +1,219 @@
 use crate::value::Value;
 use darling::FromMeta;
 use std::error::Error;
+use std::path::Path;
+
+#[cfg(feature = "cbor")]
+mod cbor;
+
+#[cfg(feature = "csv")]
+mod csv;
+
+#[cfg(feature = "dotenv")]
+mod dotenv;
+
+#[cfg(feature = "hcl")]
+mod hcl;
+
+#[cfg(feature = "hocon")]
+mod hocon;
+
+#[cfg(feature = "ini")]
+mod ini;
 
 #[cfg(feature = "json")]
 mod json;
 
+#[cfg(feature = "json5")]
+mod json5;
+
+#[cfg(feature = "kdl")]
+mod kdl;
+
+#[cfg(feature = "msgpack")]
+mod msgpack;
+
+#[cfg(feature = "plist")]
+mod plist;
+
+#[cfg(feature = "properties")]
+mod properties;
+
+#[cfg(feature = "ron")]
+mod ron;
+
 #[cfg(feature = "toml")]
 mod toml;
 
+#[cfg(feature = "xml")]
+mod xml;
+
 #[cfg(feature = "yaml")]
 mod yaml;
 
+#[cfg(feature = "yaml")]
+pub use yaml::Tag as YamlTag;
+
 #[derive(Debug, FromMeta, PartialEq)]
 pub enum Format {
+    #[cfg(feature = "cbor")]
+    Cbor,
+
+    #[cfg(feature = "csv")]
+    Csv(csv::Options),
+
+    #[cfg(feature = "dotenv")]
+    Dotenv(dotenv::Options),
+
+    #[cfg(feature = "hcl")]
+    Hcl(hcl::Options),
+
+    #[cfg(feature = "hocon")]
+    Hocon,
+
+    #[cfg(feature = "ini")]
+    Ini(ini::Options),
+
     #[cfg(feature = "json")]
     Json,
 
+    #[cfg(feature = "json5")]
+    Json5,
+
+    #[cfg(feature = "kdl")]
+    Kdl(kdl::Options),
+
+    #[cfg(feature = "msgpack")]
+    Msgpack,
+
+    #[cfg(feature = "plist")]
+    Plist,
+
+    #[cfg(feature = "properties")]
+    Properties(properties::Options),
+
+    #[cfg(feature = "ron")]
+    Ron,
+
     #[cfg(feature = "toml")]
     Toml,
 
+    #[cfg(feature = "xml")]
+    Xml(xml::Options),
+
     #[cfg(feature = "yaml")]
-    Yaml,
+    Yaml(yaml::Options),
 }
 
 impl Format {
-    pub fn parse(&self, s: &str) -> Result<Value, Box<dyn Error>> {
+    // Relative references within the source, e.g. HOCON includes, are resolved against `dir`.
+    #[cfg_attr(not(any(feature = "hocon", feature = "yaml")), allow(unused_variables))]
+    pub fn parse(&self, content: &[u8], dir: Option<&Path>) -> Result<Value, Box<dyn Error>> {
+        // Text formats require the content to be valid UTF-8.
+        let s = || std::str::from_utf8(content);
         match self {
+            #[cfg(feature = "cbor")]
+            Self::Cbor => cbor::parse(content),
+
+            #[cfg(feature = "csv")]
+            Self::Csv(options) => csv::parse(s()?, options),
+
+            #[cfg(feature = "dotenv")]
+            Self::Dotenv(options) => dotenv::parse(s()?, options),
+
+            #[cfg(feature = "hcl")]
+            Self::Hcl(options) => hcl::parse(s()?, options),
+
+            #[cfg(feature = "hocon")]
+            Self::Hocon => hocon::parse(s()?, dir),
+
+            #[cfg(feature = "ini")]
+            Self::Ini(options) => ini::parse(s()?, options),
+
             #[cfg(feature = "json")]
-            Self::Json => json::parse(s),
+            Self::Json => json::parse(s()?),
+
+            #[cfg(feature = "json5")]
+            Self::Json5 => json5::parse(s()?),
+
+            #[cfg(feature = "kdl")]
+            Self::Kdl(options) => kdl::parse(s()?, options),
+
+            #[cfg(feature = "msgpack")]
+            Self::Msgpack => msgpack::parse(content),
+
+            #[cfg(feature = "plist")]
+            Self::Plist => plist::parse(content),
+
+            #[cfg(feature = "properties")]
+            Self::Properties(options) => properties::parse(s()?, options),
+
+            #[cfg(feature = "ron")]
+            Self::Ron => ron::parse(s()?),
 
             #[cfg(feature = "toml")]
-            Self::Toml => toml::parse(s),
+            Self::Toml => toml::parse(s()?),
+
+            #[cfg(feature = "xml")]
+            Self::Xml(options) => xml::parse(s()?, options),
 
             #[cfg(feature = "yaml")]
-            Self::Yaml => yaml::parse(s),
+            Self::Yaml(options) => yaml::parse(s()?, options, dir),
         }
     }
 
     pub fn from_extension(s: &str) -> Option<Self> {
         match s {
+            #[cfg(feature = "cbor")]
+            "cbor" => Some(Self::Cbor),
+
+            #[cfg(feature = "csv")]
+            "csv" => Some(Self::Csv(Default::default())),
+
+            #[cfg(feature = "csv")]
+            "tsv" => Some(Self::Csv(csv::Options::tsv())),
+
+            #[cfg(feature = "dotenv")]
+            "env" => Some(Self::Dotenv(Default::default())),
+
+            #[cfg(feature = "hcl")]
+            "hcl" => Some(Self::Hcl(Default::default())),
+
+            #[cfg(feature = "hocon")]
+            "conf" | "hocon" => Some(Self::Hocon),
+
+            #[cfg(feature = "ini")]
+            "ini" | "cfg" => Some(Self::Ini(Default::default())),
+
             #[cfg(feature = "json")]
             "json" => Some(Self::Json),
 
+            #[cfg(feature = "json5")]
+            "json5" | "jsonc" => Some(Self::Json5),
+
+            #[cfg(feature = "kdl")]
+            "kdl" => Some(Self::Kdl(Default::default())),
+
+            #[cfg(feature = "msgpack")]
+            "msgpack" | "mp" => Some(Self::Msgpack),
+
+            #[cfg(feature = "plist")]
+            "plist" => Some(Self::Plist),
+
+            #[cfg(feature = "properties")]
+            "properties" => Some(Self::Properties(Default::default())),
+
+            #[cfg(feature = "ron")]
+            "ron" => Some(Self::Ron),
+
             #[cfg(feature = "toml")]
             "toml" => Some(Self::Toml),
 
+            #[cfg(feature = "xml")]
+            "xml" => Some(Self::Xml(Default::default())),
+
             #[cfg(feature = "yaml")]
-            "yaml" | "yml" => Some(Self::Yaml),
+            "yaml" | "yml" => Some(Self::Yaml(Default::default())),
 
             _ => None,
         }
@@ -0,0 +1,126 @@
+use crate::value::{Map, Value};
+use std::error::Error;
+
+pub fn parse(bytes: &[u8]) -> Result<Value, Box<dyn Error>> {
+    let mut rest = bytes;
+    let value = rmpv::decode::read_value(&mut rest)?;
+    if !rest.is_empty() {
+        Err("Trailing bytes after MessagePack value")?;
+    }
+    morph(value)
+}
+
+fn morph(value: rmpv::Value) -> Result<Value, Box<dyn Error>> {
+    Ok(match value {
+        rmpv::Value::Nil => Value::Nil,
+        rmpv::Value::Boolean(value) => Value::Boolean(value),
+        rmpv::Value::Integer(value) => match (value.as_u64(), value.as_i64()) {
+            (Some(value), _) => Value::PosInt(value.into()),
+            (None, Some(value)) => Value::NegInt(value.into()),
+            (None, None) => unreachable!(),
+        },
+        rmpv::Value::F32(value) => Value::Float(value.into()),
+        rmpv::Value::F64(value) => Value::Float(value),
+        rmpv::Value::String(value) => Value::String(
+            value
+                .into_str()
+                .ok_or("MessagePack strings must be valid UTF-8")?,
+        ),
+        rmpv::Value::Binary(value) => Value::Bytes(value),
+        rmpv::Value::Array(value) => {
+            Value::Array(value.into_iter().map(morph).collect::<Result<_, _>>()?)
+        }
+        rmpv::Value::Map(value) => {
+            let mut table = Map::new();
+            for (key, value) in value {
+                let key = morph_key(key)?;
+                if table.contains_key(&key) {
+                    Err(format!("Duplicate MessagePack map key `{key}`"))?;
+                }
+                table.insert(key, morph(value)?);
+            }
+            Value::Table(table)
+        }
+        // The extension type is not preserved.
+        rmpv::Value::Ext(_, value) => Value::Bytes(value),
+    })
+}
+
+fn morph_key(key: rmpv::Value) -> Result<String, Box<dyn Error>> {
+    match morph(key)? {
+        Value::Boolean(key) => Ok(key.to_string()),
+        Value::PosInt(key) => Ok(key.to_string()),
+        Value::NegInt(key) => Ok(key.to_string()),
+        Value::String(key) => Ok(key),
+        _ => Err("MessagePack map keys must be strings, integers or booleans".into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmpv::Value as MsgPack;
+
+    fn encode(value: MsgPack) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        rmpv::encode::write_value(&mut bytes, &value).unwrap();
+        bytes
+    }
+
+    fn string(s: &str) -> MsgPack {
+        MsgPack::String(s.into())
+    }
+
+    #[test]
+    fn values() {
+        let bytes = encode(MsgPack::Map(vec![
+            (string("a"), MsgPack::from(u64::MAX)),
+            (string("b"), MsgPack::from(i64::MIN)),
+            (string("c"), MsgPack::F32(1.5)),
+            (string("d"), MsgPack::Binary(vec![1, 2])),
+            (string("e"), MsgPack::Ext(1, vec![3])),
+            (
+                string("f"),
+                MsgPack::Array(vec![MsgPack::Boolean(true), MsgPack::Nil]),
+            ),
+            (MsgPack::from(-7), string("minus seven")),
+        ]));
+        let Value::Table(table) = parse(&bytes).unwrap() else {
+            panic!()
+        };
+        assert_eq!(table["a"], Value::PosInt(u64::MAX.into()));
+        assert_eq!(table["b"], Value::NegInt(i64::MIN.into()));
+        assert_eq!(table["c"], Value::Float(1.5));
+        assert_eq!(table["d"], Value::Bytes(vec![1, 2]));
+        assert_eq!(table["e"], Value::Bytes(vec![3]));
+        assert_eq!(
+            table["f"],
+            Value::Array(vec![Value::Boolean(true), Value::Nil])
+        );
+        assert_eq!(table["-7"], Value::String("minus seven".to_string()));
+    }
+
+    #[test]
+    fn colliding_keys() {
+        let bytes = encode(MsgPack::Map(vec![
+            (MsgPack::from(1), MsgPack::Nil),
+            (string("1"), MsgPack::Nil),
+        ]));
+        let error = parse(&bytes).unwrap_err();
+        assert_eq!(error.to_string(), "Duplicate MessagePack map key `1`");
+        let bytes = encode(MsgPack::Map(vec![
+            (string("a"), MsgPack::Nil),
+            (string("a"), MsgPack::Nil),
+        ]));
+        assert!(parse(&bytes).is_err());
+    }
+
+    #[test]
+    fn invalid_input() {
+        let mut bytes = encode(MsgPack::Nil);
+        bytes.push(0xc0);
+        assert!(parse(&bytes).is_err());
+        assert!(parse(&encode(MsgPack::Map(vec![(MsgPack::Nil, MsgPack::Nil)]))).is_err());
+        assert!(parse(&[0xa1, 0xff]).is_err());
+    }
+}
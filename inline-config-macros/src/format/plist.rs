@@ -0,0 +1,116 @@
+use crate::value::{Date, Datetime, Time, Value};
+use std::error::Error;
+
+// Reads XML, binary and ASCII property lists alike.
+pub fn parse(bytes: &[u8]) -> Result<Value, Box<dyn Error>> {
+    let value = plist::Value::from_reader(std::io::Cursor::new(bytes))?;
+    morph(value)
+}
+
+fn morph(value: plist::Value) -> Result<Value, Box<dyn Error>> {
+    Ok(match value {
+        plist::Value::Boolean(value) => Value::Boolean(value),
+        plist::Value::Integer(value) => match (value.as_unsigned(), value.as_signed()) {
+            (Some(value), _) => Value::PosInt(value.into()),
+            (None, Some(value)) => Value::NegInt(value.into()),
+            (None, None) => unreachable!(),
+        },
+        plist::Value::Real(value) => Value::Float(value),
+        plist::Value::String(value) => Value::String(value),
+        plist::Value::Data(value) => Value::Bytes(value),
+        plist::Value::Date(value) => Value::Datetime(datetime(value.to_xml_format())?),
+        plist::Value::Uid(value) => Value::PosInt(value.get().into()),
+        plist::Value::Array(value) => {
+            Value::Array(value.into_iter().map(morph).collect::<Result<_, _>>()?)
+        }
+        plist::Value::Dictionary(value) => Value::Table(
+            value
+                .into_iter()
+                .map(|(key, value)| morph(value).map(|value| (key, value)))
+                .collect::<Result<_, _>>()?,
+        ),
+        _ => Err("Unsupported property list value")?,
+    })
+}
+
+// Dates are points in time, written in UTC to the second like `2024-03-01T09:30:00Z`.
+fn datetime(text: String) -> Result<Datetime, Box<dyn Error>> {
+    let invalid = || format!("Invalid date `{text}`");
+    let field = |range: std::ops::Range<usize>| {
+        text.get(range)
+            .filter(|field| field.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|field| field.parse::<u16>().ok())
+            .ok_or_else(invalid)
+    };
+    if text.len() != 20 || text.as_bytes()[10] != b'T' || !text.ends_with('Z') {
+        Err(invalid())?;
+    }
+    let date = Date {
+        year: field(0..4)?,
+        month: field(5..7)? as u8,
+        day: field(8..10)? as u8,
+    };
+    let time = Time {
+        hour: field(11..13)? as u8,
+        minute: field(14..16)? as u8,
+        second: field(17..19)? as u8,
+        nanosecond: 0,
+    };
+    Ok(Datetime::new(text, Some(date), Some(time), Some(0))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates_are_datetimes_in_utc() {
+        let value = parse(
+            br#"<?xml version="1.0" encoding="UTF-8"?>
+            <plist version="1.0"><date>2024-03-01T09:30:05Z</date></plist>"#,
+        )
+        .unwrap();
+        let Value::Datetime(datetime) = value else {
+            panic!("not a datetime");
+        };
+        assert_eq!(datetime.text, "2024-03-01T09:30:05Z");
+        assert_eq!(
+            datetime.date,
+            Some(Date {
+                year: 2024,
+                month: 3,
+                day: 1,
+            })
+        );
+        assert_eq!(
+            datetime.time,
+            Some(Time {
+                hour: 9,
+                minute: 30,
+                second: 5,
+                nanosecond: 0,
+            })
+        );
+        assert_eq!(datetime.offset, Some(0));
+    }
+
+    #[test]
+    fn ascii() {
+        let value =
+            parse(br#"{ Name = Helper; Port = 8080; Version = "2.4"; Hosts = (a, "b c"); }"#)
+                .unwrap();
+        let Value::Table(table) = value else {
+            panic!("not a table");
+        };
+        assert_eq!(table["Name"], Value::String("Helper".to_string()));
+        assert_eq!(table["Port"], Value::PosInt(8080));
+        assert_eq!(table["Version"], Value::String("2.4".to_string()));
+        assert_eq!(
+            table["Hosts"],
+            Value::Array(vec![
+                Value::String("a".to_string()),
+                Value::String("b c".to_string()),
+            ])
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use crate::value::{Map, Value};
+use darling::FromMeta;
+use std::error::Error;
+
+#[derive(Debug, Default, FromMeta, PartialEq)]
+#[darling(default, from_none = || Some(Self::default()))]
+pub struct Options {
+    flat: bool,
+}
+
+// A hand-written parser following `java.util.Properties::load`.
+pub fn parse(s: &str, options: &Options) -> Result<Value, Box<dyn Error>> {
+    let mut root = Map::new();
+    let mut lines = s.lines().enumerate();
+    while let Some((index, line)) = lines.next() {
+        let mut line = line.trim_start_matches([' ', '\t', '\u{c}']).to_string();
+        if line.is_empty() || line.starts_with(['#', '!']) {
+            continue;
+        }
+        // A line ending with an odd number of backslashes continues on the next line.
+        while line.chars().rev().take_while(|&c| c == '\\').count() % 2 == 1 {
+            line.pop();
+            match lines.next() {
+                Some((_, next)) => line.push_str(next.trim_start_matches([' ', '\t', '\u{c}'])),
+                None => break,
+            }
+        }
+        let (key, value) =
+            split(&line).map_err(|e| format!("{e} in property at line {}", index + 1))?;
+        let mut names: Vec<_> = if options.flat {
+            vec![key.as_str()]
+        } else {
+            key.split('.').collect()
+        };
+        let name = names.pop().unwrap(); // Never fails.
+        let mut table = &mut root;
+        for name in names {
+            table = match table
+                .entry(name.to_string())
+                .or_insert_with(|| Value::Table(Map::new()))
+            {
+                Value::Table(table) => table,
+                _ => Err(conflict(&key))?,
+            };
+        }
+        if let Some(Value::Table(_)) = table.insert(name.to_string(), Value::String(value)) {
+            Err(conflict(&key))?;
+        }
+    }
+    Ok(Value::Table(root))
+}
+
+// Layouts like `logging.level` along with `logging.level.root` only fit flat keys.
+fn conflict(key: &str) -> String {
+    format!(
+        "Key `{key}` conflicts with another key; use `format(properties(flat))` to keep dotted keys flat"
+    )
+}
+
+fn split(line: &str) -> Result<(String, String), Box<dyn Error>> {
+    let mut chars = line.chars();
+    let mut key = String::new();
+    // The key ends at the first unescaped separator or whitespace.
+    let mut rest = "";
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescape(&mut chars, &mut key)?,
+            '=' | ':' | ' ' | '\t' | '\u{c}' => {
+                let trimmed = chars.as_str().trim_start_matches([' ', '\t', '\u{c}']);
+                rest = if matches!(c, '=' | ':') {
+                    trimmed
+                } else {
+                    trimmed
+                        .strip_prefix(['=', ':'])
+                        .map_or(trimmed, |s| s.trim_start_matches([' ', '\t', '\u{c}']))
+                };
+                break;
+            }
+            c => key.push(c),
+        }
+    }
+    let mut chars = rest.chars();
+    let mut value = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescape(&mut chars, &mut value)?,
+            c => value.push(c),
+        }
+    }
+    Ok((key, value))
+}
+
+fn unescape(chars: &mut std::str::Chars, s: &mut String) -> Result<(), Box<dyn Error>> {
+    match chars.next() {
+        Some('t') => s.push('\t'),
+        Some('n') => s.push('\n'),
+        Some('r') => s.push('\r'),
+        Some('f') => s.push('\u{c}'),
+        Some('u') => {
+            let mut code = hex_digits(chars)?;
+            // Combine a UTF-16 surrogate pair.
+            if (0xd800..0xdc00).contains(&code)
+                && let Some(rest) = chars.as_str().strip_prefix("\\u")
+            {
+                *chars = rest.chars();
+                match hex_digits(chars)? {
+                    low @ 0xdc00..0xe000 => {
+                        code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00)
+                    }
+                    _ => Err("Invalid unicode escape")?,
+                }
+            }
+            s.push(char::from_u32(code).ok_or("Invalid unicode escape")?);
+        }
+        Some(c) => s.push(c),
+        None => {}
+    }
+    Ok(())
+}
+
+fn hex_digits(chars: &mut std::str::Chars) -> Result<u32, Box<dyn Error>> {
+    let digits: String = chars.by_ref().take(4).collect();
+    u32::from_str_radix(&digits, 16)
+        .ok()
+        .filter(|_| digits.len() == 4)
+        .ok_or_else(|| format!("Invalid unicode escape `\\u{digits}`").into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(s: &str, options: &Options) -> Map<String, Value> {
+        match parse(s, options).unwrap() {
+            Value::Table(table) => table,
+            _ => panic!("not a table"),
+        }
+    }
+
+    fn string(s: &str) -> Value {
+        Value::String(s.to_string())
+    }
+
+    #[test]
+    fn separators_and_comments() {
+        let table = table(
+            "# comment\n! comment\na=1\nb : 2\nc 3\n  d\t=  4 \ne\n",
+            &Options::default(),
+        );
+        assert_eq!(table["a"], string("1"));
+        assert_eq!(table["b"], string("2"));
+        assert_eq!(table["c"], string("3"));
+        assert_eq!(table["d"], string("4 "));
+        assert_eq!(table["e"], string(""));
+        assert_eq!(table.len(), 5);
+    }
+
+    #[test]
+    fn escapes() {
+        let table = table(
+            r"key\ with\:colon\=equals = tab\there\nnewline \u00e9 \ud83d\ude00 \q",
+            &Options::default(),
+        );
+        assert_eq!(
+            table["key with:colon=equals"],
+            string("tab\there\nnewline \u{e9} \u{1f600} q")
+        );
+        assert!(parse(r"a = \u00g1", &Options::default()).is_err());
+        assert!(parse(r"a = \ud83d\u0041", &Options::default()).is_err());
+    }
+
+    #[test]
+    fn continuations() {
+        let table = table(
+            "a = one, \\\n    two, \\\n    three\nb = even \\\\\nc = last \\",
+            &Options::default(),
+        );
+        assert_eq!(table["a"], string("one, two, three"));
+        assert_eq!(table["b"], string("even \\"));
+        assert_eq!(table["c"], string("last "));
+    }
+
+    #[test]
+    fn dotted_keys_nest() {
+        let table = table("server.port = 80\nserver.host = h", &Options::default());
+        let Value::Table(server) = &table["server"] else {
+            panic!()
+        };
+        assert_eq!(server["port"], string("80"));
+        assert_eq!(server["host"], string("h"));
+    }
+
+    #[test]
+    fn flat_keys() {
+        let s = "logging.level = INFO\nlogging.level.root = DEBUG";
+        assert!(parse(s, &Options::default()).is_err());
+        let table = table(s, &Options { flat: true });
+        assert_eq!(table["logging.level"], string("INFO"));
+        assert_eq!(table["logging.level.root"], string("DEBUG"));
+    }
+}
@@ -0,0 +1,357 @@
+use crate::value::Value;
+use std::error::Error;
+
+pub fn parse(s: &str) -> Result<Value, Box<dyn Error>> {
+    let value = ron::from_str(&quote_unit_names(s)?)?;
+    morph(value)
+}
+
+const KEYWORDS: &[&str] = &[
+    "true", "false", "Some", "None", "inf", "inff32", "inff64", "NaN", "NaNf32", "NaNf64",
+];
+
+// `ron::Value` keeps no names, so unit structs and unit variants like `Fast` are quoted
+// into strings like `"Fast"` ahead of parsing. Struct names and field names stay untouched.
+// Decimal integers beyond 128 bits are rejected too, which `ron` would parse as floats.
+fn quote_unit_names(s: &str) -> Result<String, String> {
+    let mut output = String::with_capacity(s.len());
+    let mut brackets = Vec::new();
+    let mut rest = s;
+    while !rest.is_empty() {
+        let (token, len) = next_token(rest);
+        let (text, next) = rest.split_at(len);
+        match token {
+            Token::Open(c) => brackets.push(c),
+            Token::Close => {
+                brackets.pop();
+            }
+            Token::Number(number) if is_oversized_integer(number) => {
+                Err(format!("Integer `{number}` is out of the 128-bit range"))?
+            }
+            Token::Ident(name) if !KEYWORDS.contains(&name) => {
+                let follower = skip_trivia(next).chars().next();
+                let is_field = follower == Some(':') && brackets.last() != Some(&'{');
+                if follower != Some('(') && !is_field {
+                    output.push_str(&format!("{name:?}"));
+                    rest = next;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        output.push_str(text);
+        rest = next;
+    }
+    Ok(output)
+}
+
+fn is_oversized_integer(number: &str) -> bool {
+    let number = number.strip_prefix('+').unwrap_or(number).replace('_', "");
+    let digits = number.strip_prefix('-').unwrap_or(&number);
+    !digits.is_empty()
+        && digits.bytes().all(|b| b.is_ascii_digit())
+        && number.parse::<u128>().is_err()
+        && number.parse::<i128>().is_err()
+}
+
+enum Token<'a> {
+    Trivia,
+    Open(char),
+    Close,
+    Ident(&'a str),
+    Number(&'a str),
+    Other,
+}
+
+fn skip_trivia(mut s: &str) -> &str {
+    while let Some((Token::Trivia, len)) = (!s.is_empty()).then(|| next_token(s)) {
+        s = &s[len..];
+    }
+    s
+}
+
+// Lexes just enough of RON to tell identifiers from the content of literals and comments.
+// Malformed input is passed through, and left for `ron` to report.
+fn next_token(s: &str) -> (Token<'_>, usize) {
+    let ident_len = |s: &str| {
+        s.find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(s.len())
+    };
+    let mut chars = s.chars();
+    let c = chars.next().unwrap(); // Never fails.
+    let next = chars.next();
+    match c {
+        c if c.is_whitespace() => (
+            Token::Trivia,
+            s.find(|c: char| !c.is_whitespace()).unwrap_or(s.len()),
+        ),
+        '/' if next == Some('/') => (Token::Trivia, s.find('\n').unwrap_or(s.len())),
+        '/' if next == Some('*') => (Token::Trivia, block_comment_len(s)),
+        '#' if next == Some('!') => (Token::Other, s.find(']').map_or(s.len(), |i| i + 1)),
+        '"' => (Token::Other, 1 + string_len(&s[1..])),
+        '\'' => (Token::Other, 1 + char_len(&s[1..])),
+        'b' if next == Some('"') => (Token::Other, 2 + string_len(&s[2..])),
+        'b' if next == Some('\'') => (Token::Other, 2 + char_len(&s[2..])),
+        'b' if next == Some('r') && raw_string_len(&s[2..]).is_some() => {
+            (Token::Other, 2 + raw_string_len(&s[2..]).unwrap())
+        }
+        'r' if let Some(len) = raw_string_len(&s[1..]) => (Token::Other, 1 + len),
+        'r' if next == Some('#') && s[2..].starts_with(|c: char| c.is_alphabetic() || c == '_') => {
+            let len = 2 + ident_len(&s[2..]);
+            (Token::Ident(&s[2..len]), len)
+        }
+        c if c.is_alphabetic() || c == '_' => {
+            let len = ident_len(s);
+            (Token::Ident(&s[..len]), len)
+        }
+        '0'..='9' => {
+            let len = number_len(s);
+            (Token::Number(&s[..len]), len)
+        }
+        '+' | '-' | '.' if next.is_some_and(|c| c.is_ascii_digit()) => {
+            let len = 1 + number_len(&s[1..]);
+            (Token::Number(&s[..len]), len)
+        }
+        '(' | '[' | '{' => (Token::Open(c), 1),
+        ')' | ']' | '}' => (Token::Close, 1),
+        c => (Token::Other, c.len_utf8()),
+    }
+}
+
+fn block_comment_len(s: &str) -> usize {
+    let mut depth = 0;
+    let mut index = 0;
+    while index < s.len() {
+        if s[index..].starts_with("/*") {
+            depth += 1;
+            index += 2;
+        } else if s[index..].starts_with("*/") {
+            depth -= 1;
+            index += 2;
+            if depth == 0 {
+                return index;
+            }
+        } else {
+            index += s[index..].chars().next().unwrap().len_utf8(); // Never fails.
+        }
+    }
+    s.len()
+}
+
+// The length of the rest of a string after the opening quote.
+fn string_len(s: &str) -> usize {
+    let mut chars = s.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '"' => return index + 1,
+            _ => {}
+        }
+    }
+    s.len()
+}
+
+// The length of the rest of a char after the opening quote.
+fn char_len(s: &str) -> usize {
+    let start = if s.starts_with('\\') { 2 } else { 1 };
+    s.get(start..)
+        .and_then(|rest| rest.find('\''))
+        .map_or(s.len(), |index| start + index + 1)
+}
+
+// The length of a raw string after the `r`, like `#"..."#`.
+fn raw_string_len(s: &str) -> Option<usize> {
+    let hashes = s.len() - s.trim_start_matches('#').len();
+    let content = s[hashes..].strip_prefix('"')?;
+    let terminator = format!("\"{}", "#".repeat(hashes));
+    Some(
+        content
+            .find(&terminator)
+            .map_or(s.len(), |index| hashes + 1 + index + terminator.len()),
+    )
+}
+
+// Numbers may carry exponents, radix prefixes, separators and type suffixes, like `1_000u16`.
+fn number_len(s: &str) -> usize {
+    let is_hex = s.starts_with("0x");
+    let mut previous = ' ';
+    s.find(|c: char| {
+        let is_exponent_sign = matches!(c, '+' | '-') && matches!(previous, 'e' | 'E') && !is_hex;
+        previous = c;
+        !(c.is_alphanumeric() || c == '_' || c == '.' || is_exponent_sign)
+    })
+    .unwrap_or(s.len())
+}
+
+fn morph(value: ron::Value) -> Result<Value, Box<dyn Error>> {
+    Ok(match value {
+        ron::Value::Bool(value) => Value::Boolean(value),
+        ron::Value::Char(value) => Value::String(value.to_string()),
+        ron::Value::Map(value) => Value::Table(
+            value
+                .into_iter()
+                .map(|(key, value)| {
+                    morph_key(key).and_then(|key| morph(value).map(|value| (key, value)))
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        ron::Value::Number(value) => match value {
+            ron::Number::I8(value) => int(value.into()),
+            ron::Number::I16(value) => int(value.into()),
+            ron::Number::I32(value) => int(value.into()),
+            ron::Number::I64(value) => int(value.into()),
+            ron::Number::I128(value) => int(value),
+            ron::Number::U8(value) => Value::PosInt(value.into()),
+            ron::Number::U16(value) => Value::PosInt(value.into()),
+            ron::Number::U32(value) => Value::PosInt(value.into()),
+            ron::Number::U64(value) => Value::PosInt(value.into()),
+            ron::Number::U128(value) => Value::PosInt(value),
+            value => Value::Float(value.into_f64()),
+        },
+        ron::Value::Option(value) => match value {
+            Some(value) => morph(*value)?,
+            None => Value::Nil,
+        },
+        ron::Value::String(value) => Value::String(value),
+        ron::Value::Bytes(value) => Value::Bytes(value),
+        ron::Value::Seq(value) => {
+            Value::Array(value.into_iter().map(morph).collect::<Result<_, _>>()?)
+        }
+        ron::Value::Unit => Value::Nil,
+    })
+}
+
+fn int(value: i128) -> Value {
+    if value.is_negative() {
+        Value::NegInt(value)
+    } else {
+        Value::PosInt(value as u128)
+    }
+}
+
+fn morph_key(key: ron::Value) -> Result<String, Box<dyn Error>> {
+    match morph(key)? {
+        Value::Boolean(key) => Ok(key.to_string()),
+        Value::PosInt(key) => Ok(key.to_string()),
+        Value::NegInt(key) => Ok(key.to_string()),
+        Value::String(key) => Ok(key),
+        _ => Err("RON map keys must be strings, chars, integers or booleans".into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(s: &str) -> Value {
+        Value::String(s.to_string())
+    }
+
+    #[test]
+    fn unit_variants_become_names() {
+        let value = parse("(mode: Fast, fallback: Some(Slow), modes: [Fast, r#Slow])").unwrap();
+        let Value::Table(table) = value else { panic!() };
+        assert_eq!(table["mode"], string("Fast"));
+        assert_eq!(table["fallback"], string("Slow"));
+        assert_eq!(
+            table["modes"],
+            Value::Array(vec![string("Fast"), string("Slow")])
+        );
+    }
+
+    #[test]
+    fn unit_variants_as_map_keys() {
+        let value = parse("{ Fast: 1, Slow /* comment */ : 2 }").unwrap();
+        let Value::Table(table) = value else { panic!() };
+        assert_eq!(table["Fast"], Value::PosInt(1));
+        assert_eq!(table["Slow"], Value::PosInt(2));
+    }
+
+    #[test]
+    fn names_of_structs_and_fields_are_kept() {
+        let value = parse("Config(r#type: Point(x: 1), tuple: Pair(1, 2), unit: ())").unwrap();
+        let Value::Table(table) = value else { panic!() };
+        let Value::Table(point) = &table["type"] else {
+            panic!()
+        };
+        assert_eq!(point["x"], Value::PosInt(1));
+        assert_eq!(
+            table["tuple"],
+            Value::Array(vec![Value::PosInt(1), Value::PosInt(2)])
+        );
+        assert_eq!(table["unit"], Value::Nil);
+    }
+
+    #[test]
+    fn keywords_are_kept() {
+        let value = parse("[true, false, None, Some(1), -inf, NaN]").unwrap();
+        let Value::Array(values) = value else {
+            panic!()
+        };
+        assert_eq!(values[0], Value::Boolean(true));
+        assert_eq!(values[1], Value::Boolean(false));
+        assert_eq!(values[2], Value::Nil);
+        assert_eq!(values[3], Value::PosInt(1));
+        assert!(matches!(values[4], Value::Float(value) if value == f64::NEG_INFINITY));
+        assert!(matches!(values[5], Value::Float(value) if value.is_nan()));
+    }
+
+    #[test]
+    fn literals_and_comments_are_untouched() {
+        let value = parse(
+            r##"
+            // Fast
+            (
+                a: "Fast \" Slow",
+                b: r#"Fast " Slow"#,
+                c: 'F',
+                d: '\'',
+                e: b"Fast",
+                f: 0xFF,
+                g: 1e-3,
+                h: 1_000u16,
+                /* Fast /* Slow */ */
+            )
+            "##,
+        )
+        .unwrap();
+        let Value::Table(table) = value else { panic!() };
+        assert_eq!(table["a"], string("Fast \" Slow"));
+        assert_eq!(table["b"], string("Fast \" Slow"));
+        assert_eq!(table["c"], string("F"));
+        assert_eq!(table["d"], string("'"));
+        assert_eq!(table["e"], Value::Bytes(b"Fast".to_vec()));
+        assert_eq!(table["f"], Value::PosInt(255));
+        assert_eq!(table["g"], Value::Float(1e-3));
+        assert_eq!(table["h"], Value::PosInt(1000));
+    }
+
+    #[test]
+    fn extensions_are_untouched() {
+        let value = parse("#![enable(implicit_some)]\n(mode: Fast)").unwrap();
+        let Value::Table(table) = value else { panic!() };
+        assert_eq!(table["mode"], string("Fast"));
+    }
+
+    #[test]
+    fn integers_beyond_64_bits() {
+        let value = parse(
+            "(a: 18446744073709551616, b: -9223372036854775809, c: 340282366920938463463374607431768211455)",
+        );
+        let Value::Table(table) = value.unwrap() else {
+            panic!()
+        };
+        assert_eq!(table["a"], Value::PosInt(1 << 64));
+        assert_eq!(table["b"], Value::NegInt(-(1 << 63) - 1));
+        assert_eq!(table["c"], Value::PosInt(u128::MAX));
+        for text in [
+            "340282366920938463463374607431768211456",
+            "-170141183460469231731687303715884105729",
+            "1_000_000_000_000_000_000_000_000_000_000_000_000_000",
+        ] {
+            assert!(parse(text).is_err(), "{text}");
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::value::Value;
+use crate::value::{Date, Datetime, Time, Value};
 use std::error::Error;
 
 pub fn parse(s: &str) -> Result<Value, Box<dyn Error>> {
@@ -11,14 +11,31 @@ fn morph(value: toml::Value) -> Result<Value, Box<dyn Error>> {
         toml::Value::String(value) => Value::String(value),
         toml::Value::Integer(value) => {
             if value.is_negative() {
-                Value::NegInt(value)
+                Value::NegInt(value.into())
             } else {
-                Value::PosInt(value as u64)
+                Value::PosInt(value as u128)
             }
         }
         toml::Value::Float(value) => Value::Float(value),
         toml::Value::Boolean(value) => Value::Boolean(value),
-        toml::Value::Datetime(value) => Value::String(value.to_string()),
+        toml::Value::Datetime(value) => Value::Datetime(Datetime::new(
+            value.to_string(),
+            value.date.map(|date| Date {
+                year: date.year,
+                month: date.month,
+                day: date.day,
+            }),
+            value.time.map(|time| Time {
+                hour: time.hour,
+                minute: time.minute,
+                second: time.second.unwrap_or(0),
+                nanosecond: time.nanosecond.unwrap_or(0),
+            }),
+            value.offset.map(|offset| match offset {
+                toml::value::Offset::Z => 0,
+                toml::value::Offset::Custom { minutes } => minutes,
+            }),
+        )?),
         toml::Value::Array(value) => {
             Value::Array(value.into_iter().map(morph).collect::<Result<_, _>>()?)
         }
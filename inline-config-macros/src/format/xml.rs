@@ -0,0 +1,153 @@
+use crate::value::{Map, Value};
+use darling::FromMeta;
+use std::error::Error;
+
+#[derive(Debug, FromMeta, PartialEq)]
+#[darling(default, from_none = || Some(Self::default()))]
+pub struct Options {
+    attr_prefix: String,
+    text_key: String,
+    #[darling(multiple)]
+    array: Vec<String>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            attr_prefix: "@".to_string(),
+            text_key: "#text".to_string(),
+            array: Vec::new(),
+        }
+    }
+}
+
+pub fn parse(s: &str, options: &Options) -> Result<Value, Box<dyn Error>> {
+    let document = roxmltree::Document::parse(s)?;
+    let root = document.root_element();
+    let mut table = Map::new();
+    insert(&mut table, root, options)?;
+    Ok(Value::Table(table))
+}
+
+fn insert(
+    table: &mut Map<String, Value>,
+    node: roxmltree::Node,
+    options: &Options,
+) -> Result<(), Box<dyn Error>> {
+    let name = &qualified_name(node, node.tag_name().namespace(), node.tag_name().name());
+    let value = morph(node, options)?;
+    // Repeated elements form an array.
+    match table.get_mut(name) {
+        None if options.array.iter().any(|array| array == name) => {
+            table.insert(name.to_string(), Value::Array(vec![value]));
+        }
+        None => {
+            table.insert(name.to_string(), value);
+        }
+        Some(Value::Array(values)) => values.push(value),
+        Some(other) => {
+            let first = std::mem::replace(other, Value::Nil);
+            *other = Value::Array(vec![first, value]);
+        }
+    }
+    Ok(())
+}
+
+fn morph(node: roxmltree::Node, options: &Options) -> Result<Value, Box<dyn Error>> {
+    let text: String = node
+        .children()
+        .filter(roxmltree::Node::is_text)
+        .filter_map(|child| child.text())
+        .collect();
+    let text = text.trim();
+    if node.attributes().len() == 0 && !node.children().any(|child| child.is_element()) {
+        return Ok(Value::String(text.to_string()));
+    }
+    let mut table = Map::new();
+    for attribute in node.attributes() {
+        table.insert(
+            format!(
+                "{}{}",
+                options.attr_prefix,
+                qualified_name(node, attribute.namespace(), attribute.name())
+            ),
+            Value::String(attribute.value().to_string()),
+        );
+    }
+    let mut elements = Map::new();
+    for child in node.children().filter(roxmltree::Node::is_element) {
+        insert(&mut elements, child, options)?;
+    }
+    for (key, value) in elements {
+        if table.insert(key.clone(), value).is_some() {
+            Err(format!(
+                "Element `{key}` in `{}` conflicts with an attribute",
+                qualified_name(node, node.tag_name().namespace(), node.tag_name().name())
+            ))?;
+        }
+    }
+    if !text.is_empty()
+        && table
+            .insert(options.text_key.clone(), Value::String(text.to_string()))
+            .is_some()
+    {
+        Err(format!(
+            "Text of `{}` conflicts with an attribute or element",
+            qualified_name(node, node.tag_name().namespace(), node.tag_name().name())
+        ))?;
+    }
+    Ok(Value::Table(table))
+}
+
+// Names in a namespace keep the prefix bound to it, like `a:id`,
+// so that elements of different namespaces never merge.
+fn qualified_name(node: roxmltree::Node, namespace: Option<&str>, name: &str) -> String {
+    let prefix = namespace.and_then(|uri| {
+        node.lookup_prefix(uri).or_else(|| {
+            node.namespaces()
+                .filter(|namespace| namespace.uri() == uri)
+                .find_map(|namespace| namespace.name())
+        })
+    });
+    match prefix {
+        Some(prefix) => format!("{prefix}:{name}"),
+        None => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root(s: &str) -> Map<String, Value> {
+        match parse(s, &Options::default()).unwrap() {
+            Value::Table(table) => match &table["root"] {
+                Value::Table(root) => root.clone(),
+                _ => panic!("not a table"),
+            },
+            _ => panic!("not a table"),
+        }
+    }
+
+    #[test]
+    fn namespaces_keep_prefixes() {
+        let root = root(
+            r#"<root xmlns:a="urn:a" xmlns:b="urn:b" a:kind="x" xml:lang="en">
+                <a:id>1</a:id>
+                <b:id>2</b:id>
+                <id>3</id>
+            </root>"#,
+        );
+        assert_eq!(root["a:id"], Value::String("1".to_string()));
+        assert_eq!(root["b:id"], Value::String("2".to_string()));
+        assert_eq!(root["id"], Value::String("3".to_string()));
+        assert_eq!(root["@a:kind"], Value::String("x".to_string()));
+        assert_eq!(root["@xml:lang"], Value::String("en".to_string()));
+    }
+
+    #[test]
+    fn default_namespace_has_no_prefix() {
+        let root = root(r#"<root xmlns="urn:x"><id>1</id></root>"#);
+        assert_eq!(root["id"], Value::String("1".to_string()));
+    }
+}
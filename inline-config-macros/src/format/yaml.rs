@@ -1,37 +1,689 @@
-use crate::value::Value;
-use serde_yaml as yaml;
+use crate::value::{Date, Datetime, Map, Time, Value};
+use darling::FromMeta;
+use std::collections::HashMap;
 use std::error::Error;
+use std::path::{Path, PathBuf};
+use yaml_rust2::parser::{Event, Parser, Tag as EventTag};
+use yaml_rust2::scanner::{Marker, TScalarStyle};
 
-pub fn parse(s: &str) -> Result<Value, Box<dyn Error>> {
-    let value = yaml::from_str(s)?;
-    morph(value)
+#[derive(Debug, Default, FromMeta, PartialEq)]
+#[darling(default, from_none = || Some(Self::default()), and_then = Self::validate)]
+pub struct Options {
+    document: Option<usize>,
+    merge: bool,
+    array: bool,
+    // Registered through the separate `yaml_tag` attribute.
+    #[darling(skip)]
+    tags: Vec<Tag>,
 }
 
-fn morph(value: yaml::Value) -> Result<Value, Box<dyn Error>> {
-    Ok(match value {
-        yaml::Value::Null => Value::Nil,
-        yaml::Value::Bool(value) => Value::Boolean(value),
-        yaml::Value::Number(value) => value.as_u64().map(Value::PosInt).unwrap_or_else(|| {
-            value.as_i64().map(Value::NegInt).unwrap_or_else(|| {
-                Value::Float(
-                    value.as_f64().unwrap(), // Never fails.
+impl Options {
+    fn validate(self) -> darling::Result<Self> {
+        if [self.document.is_some(), self.merge, self.array]
+            .into_iter()
+            .filter(|&x| x)
+            .count()
+            > 1
+        {
+            Err(darling::Error::custom(
+                "Only one of `document`, `merge` and `array` can be specified",
+            ))
+        } else {
+            Ok(self)
+        }
+    }
+
+    pub fn with_tags(self, tags: Vec<Tag>) -> Self {
+        Self { tags, ..self }
+    }
+}
+
+#[derive(Debug, FromMeta, PartialEq)]
+#[darling(and_then = Self::validate)]
+pub struct Tag {
+    name: String,
+    action: Action,
+}
+
+impl Tag {
+    fn validate(self) -> darling::Result<Self> {
+        if self.name.len() > 1 && self.name.starts_with('!') && !self.name.starts_with("!!") {
+            Ok(self)
+        } else {
+            Err(darling::Error::custom(format!(
+                "Invalid tag `{}`, expected a local tag like `!name`",
+                self.name
+            )))
+        }
+    }
+}
+
+#[derive(Debug, FromMeta, PartialEq)]
+enum Action {
+    // Takes the scalar as the name of an environment variable, read at compile time.
+    Env,
+    // Takes the scalar as the path of another YAML file, relative to the current one.
+    Include,
+    // Drops the tag and keeps the value as is.
+    Ignore,
+}
+
+pub fn parse(s: &str, options: &Options, dir: Option<&Path>) -> Result<Value, Box<dyn Error>> {
+    let mut documents = Loader::new(s, options, dir, &[]).documents()?;
+    if let Some(index) = options.document {
+        let len = documents.len();
+        if index >= len {
+            Err(format!(
+                "Document index {index} is out of range, as there are {len} documents"
+            ))?;
+        }
+        Ok(documents.swap_remove(index))
+    } else if options.merge {
+        Ok(documents.into_iter().sum())
+    } else if options.array {
+        Ok(Value::Array(documents))
+    } else {
+        match documents.len() {
+            0 => Ok(Value::Nil),
+            1 => Ok(documents.pop().unwrap()), // Never fails.
+            len => Err(format!(
+                "Found {len} documents, specify one of `document = <INDEX>`, `merge` and `array` to handle them"
+            ))?,
+        }
+    }
+}
+
+// Aliases are expanded into copies of their anchored nodes, so a small document like
+// "billion laughs" could expand exponentially. The copies are hence limited in total,
+// while the nesting is already limited by the parser.
+const MAX_ALIASED_NODES: usize = 1 << 20;
+
+// Builds values from parser events, so that tags are visible along with the nodes they apply to.
+struct Loader<'a> {
+    parser: Parser<std::str::Chars<'a>>,
+    options: &'a Options,
+    dir: Option<&'a Path>,
+    // Files being included, to detect cycles.
+    includes: &'a [PathBuf],
+    // Anchored values along with their numbers of nodes.
+    anchors: HashMap<usize, (Value, usize)>,
+    aliased_nodes: usize,
+}
+
+impl<'a> Loader<'a> {
+    fn new(
+        s: &'a str,
+        options: &'a Options,
+        dir: Option<&'a Path>,
+        includes: &'a [PathBuf],
+    ) -> Self {
+        Self {
+            parser: Parser::new_from_str(s),
+            options,
+            dir,
+            includes,
+            anchors: HashMap::new(),
+            aliased_nodes: 0,
+        }
+    }
+
+    fn error(mark: Marker, msg: &str) -> Box<dyn Error> {
+        format!("{msg} at line {} column {}", mark.line(), mark.col() + 1).into()
+    }
+
+    fn next(&mut self) -> Result<(Event, Marker), Box<dyn Error>> {
+        Ok(self.parser.next_token()?)
+    }
+
+    fn documents(&mut self) -> Result<Vec<Value>, Box<dyn Error>> {
+        let mut documents = Vec::new();
+        loop {
+            match self.next()? {
+                (Event::StreamStart | Event::DocumentEnd, _) => {}
+                (Event::DocumentStart, _) => {
+                    let (event, mark) = self.next()?;
+                    documents.push(self.node(event, mark)?);
+                }
+                (Event::StreamEnd, _) => return Ok(documents),
+                (_, mark) => return Err(Self::error(mark, "unexpected event")),
+            }
+        }
+    }
+
+    fn node(&mut self, event: Event, mark: Marker) -> Result<Value, Box<dyn Error>> {
+        let (value, anchor) = match event {
+            Event::Alias(id) => {
+                let (value, nodes) = self
+                    .anchors
+                    .get(&id)
+                    .ok_or_else(|| Self::error(mark, "unknown anchor"))?;
+                self.aliased_nodes += nodes;
+                if self.aliased_nodes > MAX_ALIASED_NODES {
+                    return Err(Self::error(
+                        mark,
+                        &format!("aliases expanding to more than {MAX_ALIASED_NODES} nodes"),
+                    ));
+                }
+                return Ok(value.clone());
+            }
+            Event::Scalar(value, style, anchor, tag) => {
+                (self.scalar(value, style, tag, mark)?, anchor)
+            }
+            Event::SequenceStart(anchor, tag) => {
+                let mut values = Vec::new();
+                loop {
+                    match self.next()? {
+                        (Event::SequenceEnd, _) => break,
+                        (event, mark) => values.push(self.node(event, mark)?),
+                    }
+                }
+                self.collection_tag(tag.as_ref(), "sequence", &["seq", "omap", "pairs"], mark)?;
+                (Value::Array(values), anchor)
+            }
+            Event::MappingStart(anchor, tag) => {
+                let mut table = Map::new();
+                let mut merges = Vec::new();
+                loop {
+                    let (key, key_mark, value) = match self.next()? {
+                        (Event::MappingEnd, _) => break,
+                        (event, key_mark) => {
+                            let key = self.key(event, key_mark)?;
+                            let (event, mark) = self.next()?;
+                            (key, key_mark, self.node(event, mark)?)
+                        }
+                    };
+                    match key {
+                        None => merges.push(value),
+                        Some(key) => {
+                            if table.insert(key.clone(), value).is_some() {
+                                return Err(Self::error(
+                                    key_mark,
+                                    &format!("duplicate key `{key}`"),
+                                ));
+                            }
+                        }
+                    }
+                }
+                // Applies `<<` merge keys, where explicit keys and earlier merged mappings take precedence.
+                // https://yaml.org/type/merge.html
+                for merge in merges {
+                    let merges = match merge {
+                        Value::Array(merges) => merges,
+                        merge => vec![merge],
+                    };
+                    for merge in merges {
+                        match merge {
+                            Value::Table(merge) => {
+                                for (key, value) in merge {
+                                    table.entry(key).or_insert(value);
+                                }
+                            }
+                            _ => Err(Self::error(
+                                mark,
+                                "Merge keys only accept mappings or sequences of mappings",
+                            ))?,
+                        }
+                    }
+                }
+                self.collection_tag(tag.as_ref(), "mapping", &["map", "set"], mark)?;
+                (Value::Table(table), anchor)
+            }
+            _ => return Err(Self::error(mark, "unexpected event")),
+        };
+        if anchor != 0 {
+            self.anchors.insert(anchor, (value.clone(), nodes(&value)));
+        }
+        Ok(value)
+    }
+
+    // Keys take the textual form of scalars, so `8080` and `true` are keys of `"8080"` and `"true"`.
+    // Returns `None` for the `<<` merge key.
+    fn key(&mut self, event: Event, mark: Marker) -> Result<Option<String>, Box<dyn Error>> {
+        let raw = match &event {
+            Event::Scalar(value, TScalarStyle::Plain, _, None) if value == "<<" => return Ok(None),
+            // Untagged keys are kept as written, e.g. `0x10` rather than `16`.
+            Event::Scalar(value, _, _, None) => Some(value.clone()),
+            _ => None,
+        };
+        let value = self.node(event, mark)?;
+        Ok(Some(match (raw, value) {
+            (Some(raw), _) => raw,
+            (None, Value::Nil) => "null".to_string(),
+            (None, Value::Boolean(value)) => value.to_string(),
+            (None, Value::PosInt(value)) => value.to_string(),
+            (None, Value::NegInt(value)) => value.to_string(),
+            (None, Value::Float(value)) => value.to_string(),
+            (None, Value::String(value)) => value,
+            (None, Value::Datetime(value)) => value.text,
+            (None, Value::Bytes(_) | Value::Array(_) | Value::Table(_)) => {
+                return Err(Self::error(
+                    mark,
+                    "only scalars can be keys, found a complex key",
+                ));
+            }
+        }))
+    }
+
+    fn scalar(
+        &mut self,
+        value: String,
+        style: TScalarStyle,
+        tag: Option<EventTag>,
+        mark: Marker,
+    ) -> Result<Value, Box<dyn Error>> {
+        let Some(tag) = tag.map(|tag| format!("{}{}", tag.handle, tag.suffix)) else {
+            return if style == TScalarStyle::Plain {
+                resolve(&value).map_err(|e| Self::error(mark, &e))
+            } else {
+                Ok(Value::String(value))
+            };
+        };
+        let invalid = || Self::error(mark, &format!("invalid value for tag `{}`", display(&tag)));
+        match tag.strip_prefix(CORE_TAG_PREFIX) {
+            Some("str") => Ok(Value::String(value)),
+            Some("null") => (value.is_empty() || is_null(&value))
+                .then_some(Value::Nil)
+                .ok_or_else(invalid),
+            Some("bool") => parse_bool(&value).map(Value::Boolean).ok_or_else(invalid),
+            Some("int") => parse_int(&value)
+                .ok_or_else(invalid)?
+                .map_err(|e| Self::error(mark, &e)),
+            Some("float") => parse_float(&value)
+                .or_else(|| match parse_int(&value)?.ok()? {
+                    Value::PosInt(value) => Some(Value::Float(value as f64)),
+                    Value::NegInt(value) => Some(Value::Float(value as f64)),
+                    value => Some(value),
+                })
+                .ok_or_else(invalid),
+            Some("binary") => decode_base64(&value).map(Value::Bytes).ok_or_else(invalid),
+            Some("timestamp") => Ok(Value::Datetime(
+                parse_timestamp(&value)
+                    .ok_or_else(invalid)?
+                    .map_err(|e| Self::error(mark, &e))?,
+            )),
+            Some(_) => Err(Self::error(
+                mark,
+                &format!("unknown tag `{}`", display(&tag)),
+            )),
+            // The non-specific tag `!` marks a string.
+            None if tag == "!" => Ok(Value::String(value)),
+            None => match self.action(&tag, mark)? {
+                Action::Env => std::env::var(&value).map(Value::String).map_err(|e| {
+                    Self::error(mark, &format!("{e} for environment variable `{value}`"))
+                }),
+                Action::Include => self.include(&value, mark),
+                Action::Ignore => self.scalar(value, style, None, mark),
+            },
+        }
+    }
+
+    fn collection_tag(
+        &self,
+        tag: Option<&EventTag>,
+        kind: &str,
+        names: &[&str],
+        mark: Marker,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(tag) = tag.map(|tag| format!("{}{}", tag.handle, tag.suffix)) else {
+            return Ok(());
+        };
+        match tag.strip_prefix(CORE_TAG_PREFIX) {
+            Some(name) if names.contains(&name) => Ok(()),
+            Some(_) => Err(Self::error(
+                mark,
+                &format!("tag `{}` does not apply to a {kind}", display(&tag)),
+            )),
+            None if tag == "!" => Ok(()),
+            None => match self.action(&tag, mark)? {
+                Action::Ignore => Ok(()),
+                _ => Err(Self::error(
+                    mark,
+                    &format!("tag `{tag}` only applies to scalars"),
+                )),
+            },
+        }
+    }
+
+    fn action(&self, tag: &str, mark: Marker) -> Result<&'a Action, Box<dyn Error>> {
+        self.options
+            .tags
+            .iter()
+            .find(|registered| registered.name == tag)
+            .map(|registered| &registered.action)
+            .ok_or_else(|| {
+                Self::error(
+                    mark,
+                    &format!("unknown tag `{tag}`, register it with `#[config(yaml_tag(...))]`"),
                 )
             })
-        }),
-        yaml::Value::String(value) => Value::String(value),
-        yaml::Value::Sequence(value) => {
-            Value::Array(value.into_iter().map(morph).collect::<Result<_, _>>()?)
-        }
-        yaml::Value::Mapping(value) => Value::Table(
-            value
-                .into_iter()
-                .map(|(key, value)| {
-                    yaml::to_string(&key)
-                        .map_err(|e| Box::new(e) as Box<dyn Error>)
-                        .and_then(|key| morph(value).map(|value| (key, value)))
-                })
-                .collect::<Result<_, _>>()?,
-        ),
-        yaml::Value::Tagged(value) => morph(value.value)?,
-    })
+    }
+
+    fn include(&self, path: &str, mark: Marker) -> Result<Value, Box<dyn Error>> {
+        let path = match self.dir {
+            Some(dir) => dir.join(path),
+            None => PathBuf::from(path),
+        };
+        let error = |e: &dyn Error| Self::error(mark, &format!("{e} in `{}`", path.display()));
+        let canonical = path.canonicalize().map_err(|e| error(&e))?;
+        if self.includes.contains(&canonical) {
+            return Err(Self::error(
+                mark,
+                &format!("cyclic include of `{}`", path.display()),
+            ));
+        }
+        let content = std::fs::read_to_string(&path).map_err(|e| error(&e))?;
+        let includes = [self.includes, &[canonical]].concat();
+        let mut documents = Loader::new(&content, self.options, path.parent(), &includes)
+            .documents()
+            .map_err(|e| error(e.as_ref()))?;
+        match documents.len() {
+            0 => Ok(Value::Nil),
+            1 => Ok(documents.pop().unwrap()), // Never fails.
+            len => Err(Self::error(
+                mark,
+                &format!("included `{}` has {len} documents", path.display()),
+            )),
+        }
+    }
+}
+
+fn nodes(value: &Value) -> usize {
+    match value {
+        Value::Array(values) => 1 + values.iter().map(nodes).sum::<usize>(),
+        Value::Table(table) => 1 + table.values().map(nodes).sum::<usize>(),
+        _ => 1,
+    }
+}
+
+const CORE_TAG_PREFIX: &str = "tag:yaml.org,2002:";
+
+// Core tags are displayed in their shorthand form.
+fn display(tag: &str) -> String {
+    match tag.strip_prefix(CORE_TAG_PREFIX) {
+        Some(name) => format!("!!{name}"),
+        None => tag.to_string(),
+    }
+}
+
+// Resolves untagged plain scalars.
+// https://yaml.org/spec/1.2.2/#1032-tag-resolution
+fn resolve(value: &str) -> Result<Value, String> {
+    if value.is_empty() || is_null(value) {
+        Ok(Value::Nil)
+    } else if let Some(value) = parse_bool(value) {
+        Ok(Value::Boolean(value))
+    } else if let Some(int) = parse_int(value) {
+        int
+    } else if value
+        .strip_prefix(['+', '-'])
+        .unwrap_or(value)
+        .bytes()
+        .all(|b| b.is_ascii_digit())
+    {
+        // Decimal digits with leading zeros, like zip codes, are strings rather than floats.
+        Ok(Value::String(value.to_string()))
+    } else {
+        Ok(parse_float(value).unwrap_or_else(|| Value::String(value.to_string())))
+    }
+}
+
+fn is_null(value: &str) -> bool {
+    matches!(value, "~" | "null" | "Null" | "NULL")
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value {
+        "true" | "True" | "TRUE" => Some(true),
+        "false" | "False" | "FALSE" => Some(false),
+        _ => None,
+    }
+}
+
+// Fails if the integer is beyond the range of 128-bit integers.
+fn parse_int(value: &str) -> Option<Result<Value, String>> {
+    let (negative, unsigned) = match value.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (digits, radix) = if let Some(digits) = unsigned.strip_prefix("0x") {
+        (digits, 16)
+    } else if let Some(digits) = unsigned.strip_prefix("0o") {
+        (digits, 8)
+    } else if let Some(digits) = unsigned.strip_prefix("0b") {
+        (digits, 2)
+    } else if unsigned.len() > 1 && unsigned.starts_with('0') {
+        // Decimal digits with leading zeros are kept as strings.
+        return None;
+    } else {
+        (unsigned, 10)
+    };
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+    let out_of_range = || format!("Integer `{value}` is out of the 128-bit range");
+    Some(
+        u128::from_str_radix(digits, radix)
+            .map_err(|_| out_of_range())
+            .and_then(|magnitude| {
+                if !negative || magnitude == 0 {
+                    Ok(Value::PosInt(magnitude))
+                } else {
+                    0i128
+                        .checked_sub_unsigned(magnitude)
+                        .map(Value::NegInt)
+                        .ok_or_else(out_of_range)
+                }
+            }),
+    )
+}
+
+fn parse_float(value: &str) -> Option<Value> {
+    let unsigned = value.strip_prefix(['+', '-']).unwrap_or(value);
+    match unsigned {
+        ".inf" | ".Inf" | ".INF" if value.starts_with('-') => Some(Value::Float(f64::NEG_INFINITY)),
+        ".inf" | ".Inf" | ".INF" => Some(Value::Float(f64::INFINITY)),
+        ".nan" | ".NaN" | ".NAN" if unsigned == value => Some(Value::Float(f64::NAN)),
+        // Rust additionally accepts `inf` and `nan`, which are strings in YAML.
+        _ => value
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .map(Value::Float),
+    }
+}
+
+// Accepts `2001-12-14`, `2001-12-14t21:59:43.10-05:00`, `2001-12-14 21:59:43.10 -5` and alike.
+// A timestamp without a time zone is in UTC.
+// https://yaml.org/type/timestamp.html
+fn parse_timestamp(value: &str) -> Option<Result<Datetime, String>> {
+    fn digits<T: std::str::FromStr>(s: &str, min: usize, max: usize) -> Option<T> {
+        ((min..=max).contains(&s.len()) && s.bytes().all(|b| b.is_ascii_digit()))
+            .then(|| s.parse().ok())
+            .flatten()
+    }
+    let (date, time) = match value.find(['T', 't', ' ', '\t']) {
+        Some(index) => (&value[..index], Some(value[index + 1..].trim_start())),
+        None => (value, None),
+    };
+    let date = match date.split('-').collect::<Vec<_>>()[..] {
+        [year, month, day] => Date {
+            year: digits(year, 4, 4)?,
+            month: digits(month, if time.is_some() { 1 } else { 2 }, 2)?,
+            day: digits(day, if time.is_some() { 1 } else { 2 }, 2)?,
+        },
+        _ => return None,
+    };
+    let Some(time) = time else {
+        return Some(Datetime::new(value.to_string(), Some(date), None, None));
+    };
+    let (time, zone) = match time.find(['Z', 'z', '+', '-', ' ', '\t']) {
+        Some(index) => (&time[..index], time[index..].trim_start()),
+        None => (time, ""),
+    };
+    let (time, fraction) = match time.split_once('.') {
+        Some((time, fraction)) => (time, Some(fraction)),
+        None => (time, None),
+    };
+    let time = match time.split(':').collect::<Vec<_>>()[..] {
+        [hour, minute, second] => Time {
+            hour: digits(hour, 1, 2)?,
+            minute: digits(minute, 2, 2)?,
+            second: digits(second, 2, 2)?,
+            // Digits beyond nanoseconds are truncated.
+            nanosecond: match fraction {
+                None => 0,
+                Some(fraction) => {
+                    if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                        return None;
+                    }
+                    format!("{fraction:0<9}")[..9].parse().ok()?
+                }
+            },
+        },
+        _ => return None,
+    };
+    let offset = match zone.strip_prefix(['+', '-']) {
+        _ if zone.is_empty() || zone.eq_ignore_ascii_case("z") => 0,
+        Some(offset) => {
+            let (hour, minute) = offset.split_once(':').unwrap_or((offset, "00"));
+            let minutes = digits::<i16>(hour, 1, 2)? * 60 + digits::<i16>(minute, 2, 2)?;
+            if zone.starts_with('-') {
+                -minutes
+            } else {
+                minutes
+            }
+        }
+        None => return None,
+    };
+    Some(Datetime::new(
+        value.to_string(),
+        Some(date),
+        Some(time),
+        Some(offset),
+    ))
+}
+
+// Whitespace is allowed within the encoded data, as it is usually wrapped across lines.
+fn decode_base64(s: &str) -> Option<Vec<u8>> {
+    let digits: Vec<u8> = s.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    let data = digits
+        .strip_suffix(b"==")
+        .or_else(|| digits.strip_suffix(b"="))
+        .unwrap_or(&digits);
+    if !digits.len().is_multiple_of(4) {
+        return None;
+    }
+    let sextets = data
+        .iter()
+        .map(|&b| match b {
+            b'A'..=b'Z' => Some(b - b'A'),
+            b'a'..=b'z' => Some(b - b'a' + 26),
+            b'0'..=b'9' => Some(b - b'0' + 52),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(
+        sextets
+            .chunks(4)
+            .flat_map(|chunk| {
+                let bits = chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0u32, |bits, (index, &sextet)| {
+                        bits | (sextet as u32) << (18 - 6 * index)
+                    });
+                bits.to_be_bytes()[1..chunk.len()].to_vec()
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_scalars() {
+        for (text, value) in [
+            ("", Value::Nil),
+            ("~", Value::Nil),
+            ("Null", Value::Nil),
+            ("TRUE", Value::Boolean(true)),
+            ("false", Value::Boolean(false)),
+            ("0", Value::PosInt(0)),
+            ("-0", Value::PosInt(0)),
+            ("+12", Value::PosInt(12)),
+            ("-12", Value::NegInt(-12)),
+            ("0x1F", Value::PosInt(31)),
+            ("0o17", Value::PosInt(15)),
+            ("0b101", Value::PosInt(5)),
+            ("1.5", Value::Float(1.5)),
+            ("1e3", Value::Float(1e3)),
+            ("-.inf", Value::Float(f64::NEG_INFINITY)),
+            ("yes", Value::String("yes".to_string())),
+            ("inf", Value::String("inf".to_string())),
+            ("1_000", Value::String("1_000".to_string())),
+        ] {
+            assert_eq!(resolve(text), Ok(value), "{text}");
+        }
+        assert!(matches!(resolve(".nan"), Ok(Value::Float(value)) if value.is_nan()));
+    }
+
+    #[test]
+    fn leading_zeros_are_strings() {
+        for text in ["01234", "007", "-01", "+00"] {
+            assert_eq!(resolve(text), Ok(Value::String(text.to_string())), "{text}");
+        }
+        assert_eq!(resolve("01.5"), Ok(Value::Float(1.5)));
+    }
+
+    #[test]
+    fn integers_beyond_128_bits() {
+        assert_eq!(
+            resolve("340282366920938463463374607431768211455"),
+            Ok(Value::PosInt(u128::MAX))
+        );
+        assert!(resolve("340282366920938463463374607431768211456").is_err());
+        assert!(resolve("-170141183460469231731687303715884105729").is_err());
+    }
+
+    #[test]
+    fn timestamps() {
+        let datetime = parse_timestamp("2001-12-14 21:59:43.10 -5")
+            .unwrap()
+            .unwrap();
+        assert_eq!(datetime.offset, Some(-300));
+        assert_eq!(datetime.time.unwrap().nanosecond, 100_000_000);
+        let datetime = parse_timestamp("2002-12-14").unwrap().unwrap();
+        assert_eq!(datetime.time, None);
+        assert!(parse_timestamp("2002-1-14").is_none());
+        assert!(parse_timestamp("2002-02-30").unwrap().is_err());
+    }
+
+    #[test]
+    fn alias_expansion_is_limited() {
+        let parse = |s: &str| parse(s, &Options::default(), None);
+        let value = parse("a: &a [1, 2]\nb: *a\nc: [*a, *a]").unwrap();
+        let Value::Table(table) = value else { panic!() };
+        assert_eq!(table["b"], table["a"]);
+        let laughs = (1..10).fold("a0: &a0 [lol]".to_string(), |s, n| {
+            let previous = format!("*a{}", n - 1);
+            format!("{s}\na{n}: &a{n} [{}]", [previous.as_str(); 10].join(", "))
+        });
+        let error = parse(&laughs).unwrap_err();
+        assert!(
+            error.to_string().contains("more than 1048576 nodes"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn nesting_is_limited() {
+        let parse = |s: &str| parse(s, &Options::default(), None);
+        assert!(parse(&format!("{}{}", "[".repeat(100), "]".repeat(100))).is_ok());
+        let error = parse(&format!("{}{}", "[".repeat(1000), "]".repeat(1000))).unwrap_err();
+        assert!(error.to_string().contains("recursion limit"), "{error}");
+    }
 }
@@ -75,6 +75,12 @@ pub fn from_config(input: syn::ItemStruct) -> syn::Result<syn::ItemConst> {
                     }
                 }
             }
+
+            impl<#(#generics_params),*> ::inline_config::__private::Convertible for #ident<#(#generics_params),*>
+            where
+                #(#where_predicates,)*
+            {
+            }
         };
     })
 }
@@ -29,6 +29,37 @@ fn emit_tokens_or_error<T: quote::ToTokens>(result: syn::Result<T>) -> proc_macr
 ///
 /// Each format has a corresponding feature gate.
 ///
+/// Some formats accept options, which are passed in a list form instead:
+///
+/// ```ignore
+/// #[config(format(ini(untyped)))]
+/// ```
+///
+/// | Format | Options |
+/// | --- | --- |
+/// | `csv` | `delimiter = ','` - the delimiter, which is `'\t'` for `.tsv` files. |
+/// | `dotenv` | `separator = "__"` - splits keys by the separator to form nested tables. |
+/// | `hcl` | `array = "<NAME>"` - makes unlabeled blocks of this name always form arrays, even if not repeated. May be repeated. |
+/// | `ini` | `untyped` - keeps all values as strings. |
+/// | `kdl` | `array = "<NAME>"` - makes nodes of this name always form arrays, even if not repeated. May be repeated. |
+/// | `properties` | `flat` - keeps dotted keys flat instead of forming nested tables. |
+/// | `xml` | `attr_prefix = "@"` - the prefix of attribute keys.<br>`text_key = "#text"` - the key of text content.<br>`array = "<NAME>"` - makes elements of this name always form arrays, even if not repeated. May be repeated. |
+/// | `yaml` | Files of multiple documents are rejected unless one of these is specified.<br>`document = <INDEX>` - takes the document of this index.<br>`merge` - merges all documents in order.<br>`array` - forms an array of all documents. |
+///
+/// ### Attribute `yaml_tag`
+///
+/// ```ignore
+/// #[config(yaml_tag(name = "!env", action = "env"))]
+/// ```
+///
+/// Registers a custom YAML tag, which is otherwise rejected. May be repeated.
+///
+/// | Action | Effect |
+/// | --- | --- |
+/// | `env` | Reads the environment variable named by the scalar at compile time, e.g. `!env HOME`. |
+/// | `include` | Loads the YAML file at the path of the scalar, relative to the current file, e.g. `!include db.yaml`. |
+/// | `ignore` | Drops the tag and keeps the value as is. |
+///
 /// ### Attribute `src`
 ///
 /// Config sources come in three flavors:
@@ -39,6 +70,18 @@ fn emit_tokens_or_error<T: quote::ToTokens>(result: syn::Result<T>) -> proc_macr
 /// #[config(src = include_env!("<PATH_LITERAL>"))]
 /// ```
 ///
+/// Binary formats like CBOR take byte string literals `b"<SRC_LITERAL>"` for inline sources instead.
+///
+/// Any source can be wrapped in `front_matter!` to only take its front matter,
+/// e.g. the metadata block at the top of a Markdown page:
+///
+/// ```ignore
+/// #[config(src = front_matter!(include!("<PATH_LITERAL>")))]
+/// ```
+///
+/// The block is delimited by `---` lines for YAML or `+++` lines for TOML,
+/// and the format is taken from the delimiter rather than the path extension.
+///
 /// There can be an arbitrary number of sources, combined in arbitrary order, as long as they agree on the same format.
 /// When there are multiple sources, they got merged recursively per field, with latter ones overwriting former ones.
 ///
@@ -48,6 +91,33 @@ fn emit_tokens_or_error<T: quote::ToTokens>(result: syn::Result<T>) -> proc_macr
 /// The support of environment variable interpolation is to aid any code analyzer to locate files,
 /// as environment variables like `$CARGO_MANIFEST_DIR` and `$OUT_DIR` resolve to absolute paths.
 /// This is mostly inspired by [include_dir](https://docs.rs/include_dir/latest/include_dir/) crate.
+///
+/// ### Attribute `unset`
+///
+/// ```ignore
+/// #[config(unset = "server.debug")]
+/// ```
+///
+/// Removes the value at the path, written as in [`path!()`], from the merged config,
+/// so that it is no longer accessible. It is an error if there is no value at the path.
+/// Multiple `unset` attributes are allowed.
+///
+/// ### Attribute `deny_non_finite`
+///
+/// ```ignore
+/// #[config(deny_non_finite)]
+/// ```
+///
+/// Rejects `nan`, `inf` and `-inf` anywhere in the merged config, reporting the path of the first one found.
+///
+/// ### Attribute `deny_null`
+///
+/// ```ignore
+/// #[config(deny_null)]
+/// ```
+///
+/// Rejects nulls left in the merged config, i.e. those not overridden by later sources,
+/// reporting the path of the first one found.
 #[proc_macro_derive(Config, attributes(config))]
 pub fn config(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     emit_tokens_or_error(syn::parse(item).and_then(config::config))
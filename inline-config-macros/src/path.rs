@@ -73,6 +73,10 @@ impl Path {
         }
     }
 
+    pub fn keys(&self) -> &[Key] {
+        &self.keys
+    }
+
     pub fn expr(self) -> syn::Expr {
         syn::parse_quote! {
             <#self>::default()
@@ -80,6 +84,12 @@ impl Path {
     }
 }
 
+impl darling::FromMeta for Path {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        syn::parse_str(value).map_err(darling::Error::custom)
+    }
+}
+
 impl syn::parse::Parse for Path {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         Ok(if input.is_empty() {
@@ -1,19 +1,201 @@
+use crate::path::Key;
+
 #[cfg(feature = "indexmap")]
 pub(crate) type Map<K, V> = indexmap::IndexMap<K, V>;
 #[cfg(not(feature = "indexmap"))]
 pub(crate) type Map<K, V> = std::collections::BTreeMap<K, V>;
 
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
 pub enum Value {
     Nil,
     Boolean(bool),
-    PosInt(u64),
-    NegInt(i64),
+    PosInt(u128),
+    NegInt(i128),
     Float(f64),
     String(String),
+    #[cfg_attr(
+        not(any(
+            feature = "cbor",
+            feature = "msgpack",
+            feature = "plist",
+            feature = "ron",
+            feature = "yaml"
+        )),
+        allow(dead_code)
+    )]
+    Bytes(Vec<u8>),
+    #[cfg_attr(
+        not(any(feature = "plist", feature = "toml", feature = "yaml")),
+        allow(dead_code)
+    )]
+    Datetime(Datetime),
     Array(Vec<Self>),
     Table(Map<String, Self>),
 }
 
+// A date, a time, or both, optionally with an offset, kept along with its string form.
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+#[cfg_attr(
+    not(any(feature = "plist", feature = "toml", feature = "yaml")),
+    allow(dead_code)
+)]
+// Components are only read by conversions into datetime types.
+#[cfg_attr(
+    not(any(feature = "chrono", feature = "jiff", feature = "time")),
+    allow(dead_code)
+)]
+pub struct Datetime {
+    pub text: String,
+    pub date: Option<Date>,
+    pub time: Option<Time>,
+    // Offset from UTC in minutes.
+    pub offset: Option<i16>,
+}
+
+#[derive(Clone, Copy)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+#[cfg_attr(
+    not(any(feature = "chrono", feature = "jiff", feature = "time")),
+    allow(dead_code)
+)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+#[derive(Clone, Copy)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+#[cfg_attr(
+    not(any(feature = "chrono", feature = "jiff", feature = "time")),
+    allow(dead_code)
+)]
+pub struct Time {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub nanosecond: u32,
+}
+
+#[cfg_attr(
+    not(any(feature = "plist", feature = "toml", feature = "yaml")),
+    allow(dead_code)
+)]
+impl Datetime {
+    // Validates the components, so that conversions into datetime types never fail.
+    pub fn new(
+        text: String,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<i16>,
+    ) -> Result<Self, String> {
+        if let Some(Date { year, month, day }) = date {
+            let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+            let days = match month {
+                1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+                4 | 6 | 9 | 11 => 30,
+                2 if leap => 29,
+                2 => 28,
+                _ => Err(format!("Invalid month in `{text}`"))?,
+            };
+            if !(1..=days).contains(&day) {
+                Err(format!("Invalid day in `{text}`"))?;
+            }
+        }
+        if let Some(Time {
+            hour,
+            minute,
+            second,
+            nanosecond,
+        }) = time
+        {
+            if second == 60 {
+                Err(format!("Leap seconds are not supported in `{text}`"))?;
+            }
+            if hour >= 24 || minute >= 60 || second >= 60 || nanosecond >= 1_000_000_000 {
+                Err(format!("Invalid time in `{text}`"))?;
+            }
+        }
+        if offset.is_some_and(|offset| offset.abs() >= 24 * 60) {
+            Err(format!("Invalid offset in `{text}`"))?;
+        }
+        if offset.is_some() && (date.is_none() || time.is_none()) {
+            Err(format!("Offset without date and time in `{text}`"))?;
+        }
+        Ok(Self {
+            text,
+            date,
+            time,
+            offset,
+        })
+    }
+}
+
+impl Value {
+    // Removes the value at the path of keys, returning it if present.
+    pub fn remove_path(&mut self, keys: &[Key]) -> Option<Self> {
+        let (last, keys) = keys.split_last()?;
+        let parent = keys
+            .iter()
+            .try_fold(self, |value, key| match (value, key) {
+                (Self::Array(values), Key::Index(index)) => values.get_mut(*index),
+                (Self::Table(values), Key::Name(name)) => values.get_mut(name),
+                _ => None,
+            })?;
+        match (parent, last) {
+            (Self::Array(values), Key::Index(index)) => {
+                (*index < values.len()).then(|| values.remove(*index))
+            }
+            // Keeps the order of the remaining keys.
+            #[cfg(feature = "indexmap")]
+            (Self::Table(values), Key::Name(name)) => values.shift_remove(name),
+            #[cfg(not(feature = "indexmap"))]
+            (Self::Table(values), Key::Name(name)) => values.remove(name),
+            _ => None,
+        }
+    }
+
+    // Finds the first value matching the predicate, returning its path in the form of `path!()`.
+    pub fn find_path(&self, predicate: &dyn Fn(&Self) -> bool) -> Option<String> {
+        if predicate(self) {
+            return Some(String::new());
+        }
+        let children: Vec<(String, &Self)> = match self {
+            Self::Array(values) => values
+                .iter()
+                .enumerate()
+                .map(|(index, value)| (index.to_string(), value))
+                .collect(),
+            Self::Table(values) => values
+                .iter()
+                .map(|(name, value)| {
+                    let is_ident = syn::parse_str::<syn::Ident>(name).is_ok();
+                    (
+                        if is_ident {
+                            name.clone()
+                        } else {
+                            format!("{name:?}")
+                        },
+                        value,
+                    )
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        children.into_iter().find_map(|(key, value)| {
+            value.find_path(predicate).map(|path| {
+                if path.is_empty() {
+                    key
+                } else {
+                    format!("{key}.{path}")
+                }
+            })
+        })
+    }
+}
+
 impl std::ops::AddAssign for Value {
     fn add_assign(&mut self, rhs: Self) {
         match (self, rhs) {
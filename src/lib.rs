@@ -53,10 +53,39 @@
 //!
 //! See [`Config`] and [`path!()`] for specs on those macros.
 //!
+//! As later sources cannot remove values of former ones,
+//! use `#[config(unset = "...")]` to remove a value from the merged config.
+//! Accessing its path is then a compile error.
+//!
+//! ```
+//! use inline_config::{Config, path};
+//!
+//! #[derive(Config)]
+//! #[config(format = "toml")]
+//! #[config(src = "[server]\nport = 8080\n[server.debug]\nverbose = true")]
+//! #[config(unset = "server.debug")]
+//! struct MyConfig;
+//!
+//! let port: u16 = MyConfig[path!(server.port)].into();
+//! assert_eq!(8080, port);
+//! ```
+//!
+//! ```compile_fail
+//! use inline_config::{Config, path};
+//!
+//! #[derive(Config)]
+//! #[config(format = "toml")]
+//! #[config(src = "[server]\nport = 8080\n[server.debug]\nverbose = true")]
+//! #[config(unset = "server.debug")]
+//! struct MyConfig;
+//!
+//! let verbose: bool = MyConfig[path!(server.debug.verbose)].into();
+//! ```
+//!
 //! ## Compatible types
 //!
-//! Internally, data from config sources are parsed into one of the seven variants:
-//! booleans, unsigned integers, signed integers, floats, strings, arrays, tables.
+//! Internally, data from config sources are parsed into one of the nine variants:
+//! booleans, unsigned integers, signed integers, floats, strings, byte strings, datetimes, arrays, tables.
 //! Each of them has a specific storage representation, and have different compatible types.
 //!
 //! | Representation variant | Compatible types |
@@ -66,11 +95,174 @@
 //! | Signed Integer | [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`],<br>[`f32`], [`f64`] |
 //! | Float | [`f32`], [`f64`] |
 //! | String | [`&str`], [`String`] |
+//! | Byte String | `&[u8]`, `[u8; N]` of the exact length, [`Vec<u8>`] |
+//! | Datetime | [`&str`], [`String`],<br>Date, time, local datetime or offset datetime types of `chrono`, `jiff` and `time`\*\*, whichever matches the value |
 //! | Array | [`Vec<T>`] if homogeneous,<br>User-defined structs deriving [`FromConfig`] with unnamed fields |
 //! | Table | [`std::collections::BTreeMap<&str, T>`] if homogeneous,<br>[`std::collections::BTreeMap<String, T>`] if homogeneous,<br>[`indexmap::IndexMap<&str, T>`] if homogeneous\*,<br>[`indexmap::IndexMap<String, T>`] if homogeneous\*,<br>User-defined structs deriving [`FromConfig`] with named fields |
 //!
+//! Integers are kept exact within the range of 128-bit integers, and larger ones are rejected.
+//! HCL integers are further limited to 64 bits by its parser.
+//! JSON integers beyond 64 bits additionally require the `json-arbitrary-precision` feature flag,
+//! without which they are parsed as floats.
+//!
+//! ```
+//! use inline_config::{Config, path};
+//!
+//! #[derive(Config)]
+//! #[config(format = "yaml")]
+//! #[config(src = r#"
+//!     id: 0xfedcba9876543210fedcba9876543210
+//!     offset: -18446744073709551616
+//! "#)]
+//! struct Ids;
+//!
+//! let id: u128 = Ids[path!(id)].into();
+//! assert_eq!(0xfedcba9876543210fedcba9876543210, id);
+//! let offset: i128 = Ids[path!(offset)].into();
+//! assert_eq!(-18446744073709551616, offset);
+//! ```
+//!
+//! Numbers only convert into the types above that represent them exactly,
+//! so reading `300` as [`u8`] or `16777217` as [`f32`] is a compile error.
+//! Floats convert into [`f32`] as the nearest value unless out of its range,
+//! so `0.1` reads as [`f32`] while `1e300` does not.
+//! [`usize`] and [`isize`] take the pointer width of the target, so `5000000000` reads as [`usize`] on 64-bit targets only.
+//! Wrap the type in [`Lossy`] to accept an `as` cast instead.
+//!
+//! ```compile_fail
+//! use inline_config::{Config, path};
+//!
+//! #[derive(Config)]
+//! #[config(format = "toml", src = "port = 8080")]
+//! struct MyConfig;
+//!
+//! let port: u8 = MyConfig[path!(port)].into();
+//! ```
+//!
+//! ```
+//! use inline_config::{Config, path};
+//!
+//! #[derive(Config)]
+//! #[config(format = "toml", src = "max_size = 5000000000")]
+//! struct Limits;
+//!
+//! #[cfg(target_pointer_width = "64")]
+//! let max_size: usize = Limits[path!(max_size)].into();
+//!
+//! #[derive(Config)]
+//! #[config(format = "toml", src = "ratio = 0.1")]
+//! struct Ratios;
+//!
+//! let ratio: f32 = Ratios[path!(ratio)].into();
+//! assert_eq!(0.1, ratio);
+//! ```
+//!
+//! ```compile_fail
+//! use inline_config::{Config, path};
+//!
+//! #[derive(Config)]
+//! #[config(format = "toml", src = "distance = 1e300")]
+//! struct Distances;
+//!
+//! let distance: f32 = Distances[path!(distance)].into();
+//! ```
+//!
+//! Floats include non-finite ones like `nan` and `inf` of TOML and `.nan` and `.inf` of YAML.
+//! Use `#[config(deny_non_finite)]` to reject them instead.
+//! Only the merged config is checked, so a non-finite float overridden by a later source is fine.
+//!
+//! ```
+//! use inline_config::{Config, path};
+//!
+//! #[derive(Config)]
+//! #[config(format = "toml", deny_non_finite)]
+//! #[config(src = "[limits]\nratio = nan\nscale = 2.5")]
+//! #[config(src = "[limits]\nratio = 0.5")]
+//! struct Limits;
+//!
+//! let ratio: f64 = Limits[path!(limits.ratio)].into();
+//! assert_eq!(0.5, ratio);
+//! ```
+//!
+//! ```compile_fail
+//! use inline_config::Config;
+//!
+//! // error: Non-finite float at `limits.ratio`
+//! #[derive(Config)]
+//! #[config(format = "toml", deny_non_finite)]
+//! #[config(src = "[limits]\nratio = nan")]
+//! struct Limits;
+//! ```
+//!
+//! ```compile_fail
+//! use inline_config::Config;
+//!
+//! // error: Non-finite float at `scales.1`
+//! #[derive(Config)]
+//! #[config(format = "yaml", deny_non_finite)]
+//! #[config(src = "scales: [1.0, -.inf]")]
+//! struct Limits;
+//! ```
+//!
+//! Nulls left after merging convert into [`Option<T>`] as [`None`],
+//! while any other value converts into [`Option<T>`] as [`Some`] if it converts into `T`,
+//! given `T` is one of the types above.
+//! Use `#[config(deny_null)]` to reject them instead.
+//!
+//! ```
+//! use inline_config::{Config, FromConfig, path};
+//!
+//! #[derive(Config)]
+//! #[config(format = "json")]
+//! #[config(src = r#"{ "proxy": null, "timeout": null, "db": { "port": 5432 } }"#)]
+//! #[config(src = r#"{ "timeout": 30 }"#)]
+//! struct MyConfig;
+//!
+//! #[derive(FromConfig)]
+//! struct Db {
+//!     port: u16,
+//! }
+//!
+//! let proxy: Option<String> = MyConfig[path!(proxy)].into();
+//! assert_eq!(None, proxy);
+//! let timeout: Option<u32> = MyConfig[path!(timeout)].into();
+//! assert_eq!(Some(30), timeout);
+//! let db: Option<Db> = MyConfig[path!(db)].into();
+//! assert_eq!(Some(5432), db.map(|db| db.port));
+//! ```
+//!
+//! ```
+//! use inline_config::{Config, path};
+//!
+//! #[derive(Config)]
+//! #[config(format = "json", deny_null)]
+//! #[config(src = r#"{ "timeout": null }"#)]
+//! #[config(src = r#"{ "timeout": 30 }"#)]
+//! struct MyConfig;
+//!
+//! let timeout: u32 = MyConfig[path!(timeout)].into();
+//! assert_eq!(30, timeout);
+//! ```
+//!
+//! ```compile_fail
+//! use inline_config::Config;
+//!
+//! // error: Null at `db.proxy`
+//! #[derive(Config)]
+//! #[config(format = "json", deny_null)]
+//! #[config(src = r#"{ "db": { "port": 5432, "proxy": null } }"#)]
+//! struct MyConfig;
+//! ```
+//!
 //! \* Only available when enabling `indexmap` feature flag.
 //!
+//! \*\* Only available when enabling the feature flag of the same name.
+//! A date maps to `NaiveDate`, `jiff::civil::Date` or `time::Date`,
+//! a time maps to `NaiveTime`, `jiff::civil::Time` or `time::Time`,
+//! a local datetime maps to `NaiveDateTime`, `jiff::civil::DateTime` or `PrimitiveDateTime`,
+//! and an offset datetime maps to `DateTime<FixedOffset>`, `DateTime<Utc>`, `jiff::Timestamp`, `jiff::Zoned` or `OffsetDateTime`.
+//! An offset datetime later than `9999-12-30T22:00:00.999999999Z` does not map to `jiff::Timestamp` or `jiff::Zoned`, which cannot represent it.
+//!
 //! [`indexmap::IndexMap<&str, T>`]: https://docs.rs/indexmap/latest/indexmap/map/struct.IndexMap.html
 //! [`indexmap::IndexMap<String, T>`]: https://docs.rs/indexmap/latest/indexmap/map/struct.IndexMap.html
 //!
@@ -91,15 +283,342 @@
 //!
 //! [transmogrifying]: https://docs.rs/frunk/0.4.4/frunk/#transmogrifying
 //!
+//! ## Format mappings
+//!
+//! JSON, YAML and TOML map onto the representation variants in the obvious way.
+//! Other formats are mapped as follows.
+//!
+//! ### TOML
+//!
+//! Offset datetimes, local datetimes, local dates and local times are datetimes,
+//! which convert into strings as written as well as datetime types behind feature flags.
+//!
+//! ```
+//! use inline_config::{Config, path};
+//!
+//! #[derive(Config)]
+//! #[config(format = "toml")]
+//! #[config(src = r#"
+//!     released = 1979-05-27T07:32:00-08:00
+//!     birthday = 1979-05-27
+//!     alarm = 07:32:00.5
+//!     cutoff = inf
+//! "#)]
+//! struct Schedule;
+//!
+//! let released: &str = Schedule[path!(released)].into();
+//! assert_eq!("1979-05-27T07:32:00-08:00", released);
+//! let alarm: String = Schedule[path!(alarm)].into();
+//! assert_eq!("07:32:00.5", alarm);
+//! let cutoff: f64 = Schedule[path!(cutoff)].into();
+//! assert_eq!(f64::INFINITY, cutoff);
+//! ```
+//!
+//! ### YAML
+//!
+//! Anchors and aliases are expanded, up to 1048576 nodes copied by aliases in total, and `<<` merge keys are applied before mapping.
+//! Explicit keys take precedence over merged ones, and earlier mappings in a merge list take precedence over later ones.
+//!
+//! ```
+//! use inline_config::{Config, path};
+//!
+//! #[derive(Config)]
+//! #[config(format = "yaml")]
+//! #[config(src = r#"
+//!     defaults: &defaults
+//!         image: rust:latest
+//!         retries: 1
+//!     cache: &cache
+//!         retries: 2
+//!         paths: [target]
+//!     test:
+//!         <<: [*cache, *defaults]
+//!         script: cargo test
+//!     release: &release
+//!         <<: *defaults
+//!         image: rust:slim
+//!     hotfix:
+//!         <<: *release
+//!     tags: &tags [ci]
+//!     nightly:
+//!         tags: *tags
+//! "#)]
+//! struct Pipeline;
+//!
+//! let image: &str = Pipeline[path!(test.image)].into();
+//! assert_eq!("rust:latest", image);
+//! let retries: u32 = Pipeline[path!(test.retries)].into();
+//! assert_eq!(2, retries);
+//! let image: &str = Pipeline[path!(release.image)].into();
+//! assert_eq!("rust:slim", image);
+//! let retries: u32 = Pipeline[path!(hotfix.retries)].into();
+//! assert_eq!(1, retries);
+//! let tags: Vec<&str> = Pipeline[path!(nightly.tags)].into();
+//! assert_eq!(["ci"].to_vec(), tags);
+//! ```
+//!
+//! Tags are honoured rather than dropped.
+//!
+//! | Tag | Representation |
+//! | --- | --- |
+//! | `!!str`, `!` | String, even if it looks like another type |
+//! | `!!null`, `!!bool`, `!!int`, `!!float` | The corresponding type, or an error if the value does not fit |
+//! | `!!binary` | Byte string decoded from base64 |
+//! | `!!timestamp` | Datetime, in UTC if the time zone is omitted |
+//! | `!!seq`, `!!map`, `!!set`, `!!omap`, `!!pairs` | Sequence or mapping as is |
+//!
+//! Any other tag is a compile error, unless registered through `#[config(yaml_tag(...))]`.
+//!
+//! Plain scalars resolve by the YAML 1.2 core schema,
+//! except that decimal integers with leading zeros stay strings rather than octal or float numbers.
+//!
+//! ```
+//! use inline_config::{Config, path};
+//!
+//! #[derive(Config)]
+//! #[config(format = "yaml", src = "zip: 01234\ncount: 1234\nmode: 0o755")]
+//! struct Address;
+//!
+//! let zip: &str = Address[path!(zip)].into();
+//! assert_eq!("01234", zip);
+//! let count: u32 = Address[path!(count)].into();
+//! assert_eq!(1234, count);
+//! let mode: u32 = Address[path!(mode)].into();
+//! assert_eq!(0o755, mode);
+//! ```
+//!
+//! Scalar keys take their textual form as written, while sequences and mappings as keys are rejected.
+//!
+//! ```
+//! use inline_config::{Config, path};
+//!
+//! #[derive(Config)]
+//! #[config(format = "yaml")]
+//! #[config(src = r#"
+//!     status:
+//!         200: OK
+//!         404: Not Found
+//!         "500": Internal Server Error
+//!     flags:
+//!         true: enabled
+//!         0x10: mask
+//! "#)]
+//! struct Http;
+//!
+//! let status: std::collections::BTreeMap<&str, &str> = Http[path!(status)].into();
+//! assert_eq!(Some(&"Not Found"), status.get("404"));
+//! assert_eq!(["200", "404", "500"].to_vec(), status.into_keys().collect::<Vec<_>>());
+//! let flags: std::collections::BTreeMap<String, &str> = Http[path!(flags)].into();
+//! assert_eq!(["0x10", "true"].to_vec(), flags.into_keys().collect::<Vec<_>>());
+//! ```
+//!
+//! ### Java properties
+//!
+//! Dotted keys like `server.port` form nested tables, and all values are strings.
+//! Keys are separated from values by `=`, `:` or whitespace.
+//! Escapes including `\uXXXX` and line continuations are supported.
+//! A key cannot hold both a value and nested keys, as in Spring's `logging.level` along with `logging.level.root`.
+//! Use `#[config(format(properties(flat)))]` for such files to keep dotted keys flat,
+//! accessed like `path!("logging.level.root")`.
+//!
+//! ### KDL
+//!
+//! Both KDL v1 and v2 syntax are accepted. A document is a table of its nodes.
+//!
+//! | KDL | Representation |
+//! | --- | --- |
+//! | `node 1` | The argument |
+//! | `node 1 2 3` | Array of the arguments |
+//! | `node key=1 { child 2 }` | Table of properties and children |
+//! | `node "a" "b" key=1 { child 2 }` | Table of properties and children, nested under labels `a` and `b` |
+//! | `node` | Empty table |
+//!
+//! Repeated nodes form an array of their values, as repeated XML elements do,
+//! while labeled nodes are keyed by their labels, which cannot be repeated.
+//! Nodes listed in `#[config(format(kdl(array = "node")))]` always form an array, even if not repeated,
+//! collecting arguments of all of them, or tables of those with properties or children.
+//! Thus `ports 8000` along with `ports 8001 8002` gives `[8000, 8001, 8002]`, and a single `ports 8000` still gives `[8000]`.
+//!
+//! Type annotations are ignored. `true`, `false`, `null` and their v2 forms `#true`, `#false`, `#null` are keywords;
+//! other bare identifiers in value positions are strings.
+//!
+//! ### MessagePack
+//!
+//! | MessagePack | Representation variant |
+//! | --- | --- |
+//! | `true`, `false` | Boolean |
+//! | Integers, floats | Unsigned Integer, Signed Integer, Float |
+//! | Strings | String |
+//! | Binaries, extensions | Byte String (the extension type is ignored) |
+//! | Arrays | Array |
+//! | Maps | Table, keys being strings, integers or booleans |
+//! | `nil` | Null |
+//!
+//! Map keys are converted into strings, and keys like `1` and `"1"` colliding that way are rejected.
+//!
+//! ### Property lists
+//!
+//! XML, binary and ASCII property lists are all accepted.
+//! ASCII property lists only have integers, strings, arrays and dictionaries.
+//!
+//! | Property list | Representation variant |
+//! | --- | --- |
+//! | `<true/>`, `<false/>` | Boolean |
+//! | `<integer>`, `<real>` | Unsigned Integer, Signed Integer, Float |
+//! | `<string>` | String |
+//! | `<date>` | Datetime in UTC, e.g. `2024-03-01T09:30:00Z` |
+//! | `<data>` | Byte String |
+//! | `<array>` | Array |
+//! | `<dict>` | Table |
+//! | UIDs of keyed archives | Unsigned Integer |
+//!
+//! ### RON
+//!
+//! | RON | Representation variant |
+//! | --- | --- |
+//! | `true`, `false` | Boolean |
+//! | Integers, floats | Unsigned Integer, Signed Integer, Float |
+//! | Strings, chars | String |
+//! | Byte strings | Byte String |
+//! | Structs `Name(a: 1)`, struct variants | Table (the name is ignored) |
+//! | Tuples `(1, 2)`, tuple structs, tuple variants | Array (the name is ignored) |
+//! | Maps `{ "a": 1 }` | Table, keys being strings, chars, integers or booleans |
+//! | `Some(x)` | Same as `x` |
+//! | Unit structs, unit variants `Fast` | String of the name |
+//! | `None`, `()` | Null |
+//!
+//! ### CBOR
+//!
+//! | CBOR | Representation variant |
+//! | --- | --- |
+//! | `true`, `false` | Boolean |
+//! | Integers, floats | Unsigned Integer, Signed Integer, Float |
+//! | Bignums (tags 2 and 3) within 128 bits | Unsigned Integer, Signed Integer |
+//! | Text strings | String |
+//! | Byte strings | Byte String |
+//! | Arrays | Array |
+//! | Maps | Table, keys being strings, integers or booleans |
+//! | Other tagged items | Same as the content (the tag is ignored) |
+//! | `null`, `undefined` | Null |
+//!
+//! Map keys are converted into strings, and keys like `1` and `"1"` colliding that way are rejected.
+//!
+//! ### CSV
+//!
+//! A file forms an array of rows, each being a table keyed by the header.
+//! Cells are booleans, integers or floats if they parse as such, otherwise strings.
+//! Numbers are only recognized in canonical form, so cells like `007` and `+1` stay strings.
+//! Empty cells are null.
+//!
+//! Files with extension `.tsv` are separated by tabs.
+//! Other delimiters can be configured, e.g. `#[config(format(csv(delimiter = ';')))]`.
+//!
+//! ### dotenv
+//!
+//! The file forms a table of strings. `export` prefixes and comments are ignored.
+//! Single-quoted values are taken literally,
+//! while double-quoted values support escapes like `\n` and may span multiple lines.
+//! `${VAR}` and `$VAR` in unquoted or double-quoted values refer to keys defined earlier in the same file;
+//! the environment is never consulted.
+//! A `$` starting no reference, like in `$5`, is kept literally.
+//! Use `#[config(format(dotenv(separator = "__")))]` to map keys like `DB__HOST` into nested tables.
+//!
+//! A file named `.env`, `.env.*` like `.env.local`, or `*.env` is recognized as dotenv.
+//!
+//! ### HCL
+//!
+//! Attributes and blocks are table entries, and labeled blocks like `service "http" { ... }` nest by their labels.
+//! Hence labeled blocks are always tables keyed by their labels, and the same labels must not repeat.
+//! An unlabeled block is a table, and repeated unlabeled blocks form an array of tables, as repeated XML elements do.
+//! Blocks whose name is listed like `#[config(format(hcl(array = "rule")))]` always form an array, even if not repeated.
+//! Only literal expressions are supported;
+//! variables, function calls, operators and template interpolations cause compile errors.
+//!
+//! ### HOCON
+//!
+//! Substitutions, object merging, path expressions like `a.b.c = 1`, `+=` and value concatenations
+//! are resolved at compile time, so the config maps onto the representation like JSON.
+//! Substitutions missing from the config fall back to environment variables at compile time, as strings,
+//! so `${?PORT}` overrides a default if `PORT` is set when building.
+//! `+=` never takes an environment variable as the previous value.
+//!
+//! `include` statements resolve file paths relative to the including file, or to the current file for inline sources.
+//! A path without an extension includes both its `.conf` and `.json` files if present.
+//! `url(...)` and `classpath(...)` includes are not supported.
+//!
+//! ### INI
+//!
+//! Sections are tables, and dotted section names like `[server.tls]` nest.
+//! Keys outside any section belong to the root table.
+//! Quoted values are strings.
+//! Unquoted values are booleans, or integers in canonical form, otherwise strings.
+//! Hence values like `007`, `+5` and `-0` stay strings.
+//! Use `#[config(format(ini(untyped)))]` to keep all values as strings.
+//!
+//! ### XML
+//!
+//! A document is a table with the root element as its only entry.
+//! An element with neither attributes nor child elements is its text content, trimmed.
+//! Otherwise, it is a table of its attributes, child elements and text content.
+//! Attribute keys are prefixed with `@`, and the text content is under key `#text`.
+//! Repeated elements form an array. All values are strings.
+//! Names in a namespace keep their prefix, so `<a:id>` and `<b:id>` are under keys `a:id` and `b:id`,
+//! while names in the default namespace have none.
+//!
+//! The conventions can be configured, e.g. `#[config(format(xml(attr_prefix = "_", text_key = "value", array = "item")))]`.
+//!
+//! ## Unsupported formats
+//!
+//! Formats that are programs rather than data need an evaluator at compile time,
+//! and none is bundled, as it would pull a whole interpreter into the macros crate.
+//! Render such configs into a supported format first, e.g. in a build script, and include the output.
+//!
+//! * Jsonnet - render with `jsonnet -o config.json config.jsonnet`, passing `--ext-str` for `std.extVar` bindings.
+//! * Dhall - render with `dhall-to-json --file config.dhall --output config.json`, which also type-checks.
+//!
 //! ## Feature flags
 //!
 //! * `json` - supports JSON file format. Enabled by default.
 //! * `yaml` - supports YAML file format. Enabled by default.
 //! * `toml` - supports TOML file format. Enabled by default.
+//! * `cbor` - supports CBOR file format.
+//! * `csv` - supports CSV and TSV file formats.
+//! * `dotenv` - supports dotenv file format.
+//! * `hcl` - supports HCL file format.
+//! * `hocon` - supports HOCON file format.
+//! * `ini` - supports INI file format.
+//! * `json-arbitrary-precision` - keeps JSON integers beyond 64 bits exact.
+//! * `json5` - supports JSON5 file format, which also covers JSON with comments.
+//! * `kdl` - supports KDL file format.
+//! * `msgpack` - supports MessagePack file format.
+//! * `plist` - supports property list file format.
+//! * `properties` - supports Java properties file format.
+//! * `ron` - supports RON file format.
+//! * `xml` - supports XML file format.
 //! * `indexmap` - enables preserving orders of tables.
+//! * `chrono` - enables converting datetimes into `chrono` types.
+//! * `jiff` - enables converting datetimes into `jiff` types.
+//! * `time` - enables converting datetimes into `time` types.
 
 pub use inline_config_macros::*;
 
+/// Accepts a number as `T` even if `T` cannot represent it exactly, converting with an `as` cast.
+///
+/// Numbers otherwise only convert into types representing them exactly.
+///
+/// ```
+/// use inline_config::{Config, Lossy, path};
+///
+/// #[derive(Config)]
+/// #[config(format = "toml", src = "port = 8080")]
+/// struct MyConfig;
+///
+/// let Lossy(port): Lossy<u8> = MyConfig[path!(port)].into();
+/// assert_eq!(144, port);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Lossy<T>(pub T);
+
 #[doc(hidden)]
 pub mod __private {
     use std::marker::PhantomData;
@@ -141,4 +660,74 @@ pub mod __private {
 
     #[derive(Default)]
     pub struct PathCons<K, P>(K, P);
+
+    // Types which config values convert into, so that they convert into `Option<T>` as well.
+    // Config types never implement it, keeping those impls apart from `From<T> for Option<T>`.
+    pub trait Convertible {}
+
+    macro_rules! impl_convertible {
+        ($($ty:ty),* $(,)?) => {
+            $(impl Convertible for $ty {})*
+        };
+    }
+
+    impl_convertible!(
+        bool,
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        usize,
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        isize,
+        f32,
+        f64,
+        &'static str,
+        String,
+        &'static [u8],
+    );
+
+    impl<const N: usize> Convertible for [u8; N] {}
+
+    impl<T> Convertible for Vec<T> {}
+
+    impl<K, T> Convertible for std::collections::BTreeMap<K, T> {}
+
+    impl<T> Convertible for Option<T> {}
+
+    impl<T> Convertible for crate::Lossy<T> {}
+
+    #[cfg(feature = "indexmap")]
+    impl<K, T> Convertible for indexmap::IndexMap<K, T> {}
+
+    #[cfg(feature = "chrono")]
+    impl_convertible!(
+        chrono::NaiveDate,
+        chrono::NaiveTime,
+        chrono::NaiveDateTime,
+        chrono::DateTime<chrono::FixedOffset>,
+        chrono::DateTime<chrono::Utc>,
+    );
+
+    #[cfg(feature = "jiff")]
+    impl_convertible!(
+        jiff::civil::Date,
+        jiff::civil::Time,
+        jiff::civil::DateTime,
+        jiff::Timestamp,
+        jiff::Zoned,
+    );
+
+    #[cfg(feature = "time")]
+    impl_convertible!(
+        time::Date,
+        time::Time,
+        time::PrimitiveDateTime,
+        time::OffsetDateTime,
+    );
 }